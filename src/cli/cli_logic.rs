@@ -1,7 +1,52 @@
-use clap::{Arg, Command};
-use serde::Deserialize;
+use clap::builder::{EnumValueParser, PossibleValue};
+use clap::{Arg, ArgAction, Command, ValueEnum};
 
-pub fn parse_args() -> Config {
+pub use super::config_logic::{load_config, read_config, ActionConfig, Config};
+use super::config_logic::{LogLevel, LoggingConfig};
+use super::validation_logic::validate_config;
+use crate::features::{
+    create_feature, get_available_features, get_feature_description, ScreensaverFeature,
+};
+use crate::shared::{PlaylistRotator, ScreensaverError, ScreensaverRuntime, SeededRng};
+use std::time::Instant;
+
+pub struct CliArgs {
+    pub config_path: String,
+    pub check_config: bool,
+    pub feature: Option<String>,
+    pub list_features: bool,
+    pub watch: bool,
+    pub seed: Option<u64>,
+    pub fps: Option<u32>,
+    pub log_level: Option<LogLevel>,
+    pub log_file: Option<String>,
+}
+
+impl ValueEnum for LogLevel {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            LogLevel::Off,
+            LogLevel::Error,
+            LogLevel::Warn,
+            LogLevel::Info,
+            LogLevel::Debug,
+            LogLevel::Trace,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(PossibleValue::new(match self {
+            LogLevel::Off => "off",
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }))
+    }
+}
+
+pub fn parse_args() -> CliArgs {
     let matches = Command::new("Terminal Screensaver")
         .version("0.0.1")
         .author("Your Name")
@@ -14,49 +59,190 @@ pub fn parse_args() -> Config {
                 .help("Sets the config file")
                 .num_args(1),
         )
+        .arg(
+            Arg::new("check-config")
+                .long("check-config")
+                .help("Validates the config file and exits non-zero on errors")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("feature")
+                .short('f')
+                .long("feature")
+                .value_name("NAME")
+                .help("Runs the named feature instead of [screensaver] default_feature")
+                .num_args(1),
+        )
+        .arg(
+            Arg::new("list-features")
+                .long("list-features")
+                .help("Lists the available features and exits")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("watch")
+                .short('w')
+                .long("watch")
+                .help("Waits for [screensaver] timeout_seconds without input, then starts the screensaver; any key dismisses it")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("seed")
+                .long("seed")
+                .value_name("N")
+                .help("Seeds the random number generator so a run can be reproduced")
+                .value_parser(clap::value_parser!(u64))
+                .num_args(1),
+        )
+        .arg(
+            Arg::new("fps")
+                .long("fps")
+                .value_name("N")
+                .help("Caps rendering at N frames per second (1-240, default 20); animation speed is unaffected")
+                .value_parser(clap::value_parser!(u32).range(1..=240))
+                .num_args(1),
+        )
+        .arg(
+            Arg::new("log-level")
+                .long("log-level")
+                .value_name("LEVEL")
+                .help("Overrides [logging] level")
+                .value_parser(EnumValueParser::<LogLevel>::new())
+                .num_args(1),
+        )
+        .arg(
+            Arg::new("log-file")
+                .long("log-file")
+                .value_name("FILE")
+                .help("Overrides [logging] file; \"none\" turns file logging off")
+                .num_args(1),
+        )
         .get_matches();
 
     let config_path = matches
         .get_one::<String>("config")
-        .map(|s| s.as_str())
-        .unwrap_or("terminal-screensaver.toml");
-    load_config(config_path)
-}
-
-#[derive(Debug, Deserialize)]
-pub struct Config {
-    pub text: String,
-    pub style: String,
-    #[serde(default)]
-    pub actions: Vec<ActionConfig>,
-}
-
-#[derive(Debug, Deserialize, Clone)]
-pub struct ActionConfig {
-    pub key: String,
-    pub description: String,
-    pub command: String,
-}
-
-fn load_config(path: &str) -> Config {
-    let content = std::fs::read_to_string(path).unwrap_or_else(|_| {
-        log::warn!("Config file not found, using defaults");
-        "".to_string()
-    });
-    if content.is_empty() {
-        Config {
-            text: "Welcome to Terminal Screensaver".to_string(),
-            style: "default".to_string(),
-            actions: Vec::new(),
+        .cloned()
+        .unwrap_or_else(|| "terminal-screensaver.toml".to_string());
+
+    CliArgs {
+        config_path,
+        check_config: matches.get_flag("check-config"),
+        feature: matches.get_one::<String>("feature").cloned(),
+        list_features: matches.get_flag("list-features"),
+        watch: matches.get_flag("watch"),
+        seed: matches.get_one::<u64>("seed").copied(),
+        fps: matches.get_one::<u32>("fps").copied(),
+        log_level: matches.get_one::<LogLevel>("log-level").copied(),
+        log_file: matches.get_one::<String>("log-file").cloned(),
+    }
+}
+
+// The [logging] settings with --log-level and --log-file applied on top
+pub fn logging_config(args: &CliArgs, config: &Config) -> LoggingConfig {
+    let mut logging = config.logging.clone();
+    if let Some(level) = args.log_level {
+        logging.level = level;
+    }
+    if let Some(file) = &args.log_file {
+        logging.file = Some(file.clone());
+    }
+    logging
+}
+
+pub fn list_features() {
+    println!("Available features:");
+    for name in get_available_features() {
+        println!(
+            "  {:<16} {}",
+            name,
+            get_feature_description(name).unwrap_or("")
+        );
+    }
+}
+
+// Pick the feature requested on the command line, falling back to the config default
+pub fn select_feature(
+    requested: Option<&str>,
+    config: &Config,
+    rng: &mut SeededRng,
+) -> Result<Box<dyn ScreensaverFeature>, ScreensaverError> {
+    let name = requested.unwrap_or(&config.screensaver.default_feature);
+
+    if !get_available_features().contains(&name) {
+        return Err(ScreensaverError::Feature(format!(
+            "unknown feature `{}` (available: {})",
+            name,
+            get_available_features().join(", ")
+        )));
+    }
+    if !config.features.is_enabled(name) {
+        return Err(ScreensaverError::Feature(format!(
+            "feature `{}` is disabled in the config ([features.{}] enabled = false)",
+            name, name
+        )));
+    }
+
+    create_feature(name, config, rng)
+        .ok_or_else(|| ScreensaverError::Feature(format!("feature `{}` is not available", name)))
+}
+
+// Report every diagnostic for the config file and return the process exit code
+pub fn check_config(path: &str) -> i32 {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("{}: error: cannot read config file: {}", path, e);
+            return 1;
         }
-    } else {
-        toml::from_str(&content).unwrap_or_else(|e| {
-            log::error!("Failed to parse config: {}", e);
-            Config {
-                text: "Welcome to Terminal Screensaver".to_string(),
-                style: "default".to_string(),
-                actions: Vec::new(),
+    };
+
+    match validate_config(&content, path) {
+        Ok((_, warnings)) => {
+            for warning in &warnings {
+                eprintln!("{}", warning);
+            }
+            println!("{}: configuration is valid", path);
+            0
+        }
+        Err(diagnostics) => {
+            for diagnostic in &diagnostics {
+                eprintln!("{}", diagnostic);
             }
-        })
+            let errors = diagnostics.iter().filter(|d| d.is_error()).count();
+            eprintln!("{}: {} error(s) found", path, errors);
+            1
+        }
+    }
+}
+
+// Whether this run rotates through [playlist] rather than showing one feature
+pub fn uses_playlist(requested: Option<&str>, config: &Config) -> bool {
+    requested.is_none() && config.playlist.is_active()
+}
+
+// Check the feature or playlist can be started, so problems are reported
+// before the terminal is taken over
+pub fn check_selection(requested: Option<&str>, config: &Config) -> Result<(), ScreensaverError> {
+    if uses_playlist(requested, config) {
+        PlaylistRotator::new(config, Instant::now(), SeededRng::new(0)).map(|_| ())
+    } else {
+        select_feature(requested, config, &mut SeededRng::new(0)).map(|_| ())
+    }
+}
+
+// Run the playlist or the selected feature until the user exits
+pub fn start_screensaver(
+    runtime: &ScreensaverRuntime,
+    requested: Option<&str>,
+    config: &Config,
+    rng: &mut SeededRng,
+) -> Result<(), ScreensaverError> {
+    if uses_playlist(requested, config) {
+        let rotator = PlaylistRotator::new(config, Instant::now(), rng.fork())?;
+        runtime.run_playlist(rotator)
+    } else {
+        let feature = select_feature(requested, config, rng)?;
+        log::info!("Starting feature: {}", feature.name());
+        runtime.run(feature)
     }
 }
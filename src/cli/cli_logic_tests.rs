@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod cli_tests {
+    use crate::cli::cli_logic::*;
+    use crate::cli::config_logic::parse_config;
+    use crate::shared::SeededRng;
+
+    #[test]
+    fn test_select_feature_uses_config_default() {
+        let config = parse_config("[screensaver]\ndefault_feature = \"bouncing_logo\"\n").unwrap();
+
+        let feature = select_feature(None, &config, &mut SeededRng::new(1)).unwrap();
+        assert_eq!(feature.name(), "bouncing_logo");
+    }
+
+    #[test]
+    fn test_select_feature_prefers_command_line() {
+        let config = Config::default();
+
+        let feature =
+            select_feature(Some("bouncing_logo"), &config, &mut SeededRng::new(1)).unwrap();
+        assert_eq!(feature.name(), "bouncing_logo");
+    }
+
+    #[test]
+    fn test_select_feature_rejects_unknown_and_disabled() {
+        let config = parse_config("[features.bouncing_logo]\nenabled = false\n").unwrap();
+
+        let unknown = select_feature(Some("lava_lamp"), &config, &mut SeededRng::new(1))
+            .err()
+            .unwrap();
+        assert!(unknown.to_string().contains("unknown feature `lava_lamp`"));
+
+        let disabled = select_feature(Some("bouncing_logo"), &config, &mut SeededRng::new(1))
+            .err()
+            .unwrap();
+        assert!(disabled.to_string().contains("disabled"));
+    }
+
+    #[test]
+    fn test_every_listed_feature_can_be_created() {
+        let config = Config::default();
+
+        for name in crate::features::get_available_features() {
+            let feature =
+                crate::features::create_feature(name, &config, &mut SeededRng::new(1)).unwrap();
+            assert_eq!(feature.name(), name);
+            assert!(crate::features::get_feature_description(name).is_some());
+        }
+    }
+}
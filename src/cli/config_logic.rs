@@ -0,0 +1,496 @@
+use super::validation_logic::{validate_config, Diagnostic};
+use crate::shared::ScreensaverError;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+
+// Typed view of terminal-screensaver.toml
+//
+// Top-level `text`/`style`/`actions` keep the original flat layout working,
+// while `[screensaver]`, `[features.*]` and `[custom_actions]` carry the
+// documented per-feature settings.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct Config {
+    pub text: String,
+    pub style: String,
+    pub screensaver: ScreensaverConfig,
+    pub logging: LoggingConfig,
+    pub themes: HashMap<String, ThemeConfig>,
+    pub features: FeaturesConfig,
+    pub playlist: PlaylistConfig,
+    pub custom_actions: HashMap<String, String>,
+    pub actions: Vec<ActionConfig>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            text: "Welcome to Terminal Screensaver".to_string(),
+            style: "default".to_string(),
+            screensaver: ScreensaverConfig::default(),
+            logging: LoggingConfig::default(),
+            themes: HashMap::new(),
+            features: FeaturesConfig::default(),
+            playlist: PlaylistConfig::default(),
+            custom_actions: HashMap::new(),
+            actions: Vec::new(),
+        }
+    }
+}
+
+impl Config {
+    // Text shown by text_display: the feature section wins over the legacy top-level key
+    pub fn display_text(&self) -> &str {
+        self.features
+            .text_display
+            .text
+            .as_deref()
+            .unwrap_or(&self.text)
+    }
+
+    // Style used by text_display: the feature section wins over the legacy top-level key
+    pub fn display_style(&self) -> &str {
+        self.features
+            .text_display
+            .style
+            .as_deref()
+            .unwrap_or(&self.style)
+    }
+}
+
+// An `[[actions]]` entry. Exactly one of `command` (run through `bash -c`)
+// or `argv` (run directly, no shell) is set; validation enforces this.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ActionConfig {
+    pub key: String,
+    pub description: String,
+    #[serde(default)]
+    pub command: String,
+    #[serde(default)]
+    pub argv: Vec<String>,
+    #[serde(default)]
+    pub cwd: Option<String>,
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    #[serde(default)]
+    pub timeout_seconds: Option<u64>,
+    #[serde(default)]
+    pub confirm: bool,
+    // Wait for a key after the command finishes before returning to the screensaver
+    #[serde(default = "default_pause")]
+    pub pause: bool,
+    #[serde(default)]
+    pub output: ActionOutput,
+}
+
+// Where an action's output goes: the whole terminal, or a pane drawn over the
+// running feature so the animation keeps going
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ActionOutput {
+    #[default]
+    Terminal,
+    Pane,
+}
+
+fn default_pause() -> bool {
+    true
+}
+
+impl Default for ActionConfig {
+    fn default() -> Self {
+        Self {
+            key: String::new(),
+            description: String::new(),
+            command: String::new(),
+            argv: Vec::new(),
+            cwd: None,
+            env: HashMap::new(),
+            timeout_seconds: None,
+            confirm: false,
+            pause: default_pause(),
+            output: ActionOutput::Terminal,
+        }
+    }
+}
+
+impl ActionConfig {
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout_seconds.map(Duration::from_secs)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ScreensaverConfig {
+    pub default_feature: String,
+    pub timeout_seconds: u64,
+    pub alternate_screen: bool,
+    pub mouse_capture: bool,
+    pub theme: String,
+}
+
+impl Default for ScreensaverConfig {
+    fn default() -> Self {
+        Self {
+            default_feature: "text_display".to_string(),
+            timeout_seconds: 60,
+            alternate_screen: true,
+            mouse_capture: false,
+            theme: "default".to_string(),
+        }
+    }
+}
+
+// A theme role: a color name, or a table with `fg`, `bg` and `attributes`
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum RoleConfig {
+    Color(String),
+    Style {
+        fg: Option<String>,
+        bg: Option<String>,
+        #[serde(default)]
+        attributes: Vec<String>,
+    },
+}
+
+// A `[themes.<name>]` table; roles left out come from the bundled `base` theme
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ThemeConfig {
+    pub base: Option<String>,
+    pub primary: Option<RoleConfig>,
+    pub accent: Option<RoleConfig>,
+    pub help_border: Option<RoleConfig>,
+    pub help_text: Option<RoleConfig>,
+    pub dim: Option<RoleConfig>,
+    pub success: Option<RoleConfig>,
+    pub warning: Option<RoleConfig>,
+    pub error: Option<RoleConfig>,
+    pub background: Option<String>,
+    pub palette: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Off,
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    pub fn filter(&self) -> log::LevelFilter {
+        match self {
+            LogLevel::Off => log::LevelFilter::Off,
+            LogLevel::Error => log::LevelFilter::Error,
+            LogLevel::Warn => log::LevelFilter::Warn,
+            LogLevel::Info => log::LevelFilter::Info,
+            LogLevel::Debug => log::LevelFilter::Debug,
+            LogLevel::Trace => log::LevelFilter::Trace,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    // Time, level and message
+    #[default]
+    Timestamped,
+    // Level and message only
+    Plain,
+}
+
+// `[logging]`: without `file` the log goes to the XDG state directory;
+// `file = "none"` turns file logging off
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct LoggingConfig {
+    pub level: LogLevel,
+    pub file: Option<String>,
+    pub format: LogFormat,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PlaylistOrder {
+    Sequential,
+    Shuffle,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PlaylistTransition {
+    Cut,
+    Wipe,
+}
+
+// `[playlist]` rotates through `[[playlist.entries]]` when no feature is
+// picked with --feature; an empty playlist runs `default_feature` alone.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PlaylistConfig {
+    pub order: PlaylistOrder,
+    pub transition: PlaylistTransition,
+    pub default_duration_seconds: u64,
+    pub entries: Vec<PlaylistEntry>,
+}
+
+impl Default for PlaylistConfig {
+    fn default() -> Self {
+        Self {
+            order: PlaylistOrder::Sequential,
+            transition: PlaylistTransition::Cut,
+            default_duration_seconds: 60,
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl PlaylistConfig {
+    pub fn is_active(&self) -> bool {
+        !self.entries.is_empty()
+    }
+
+    pub fn duration_of(&self, entry: &PlaylistEntry) -> Duration {
+        Duration::from_secs(
+            entry
+                .duration_seconds
+                .unwrap_or(self.default_duration_seconds),
+        )
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PlaylistEntry {
+    pub feature: String,
+    pub duration_seconds: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct FeaturesConfig {
+    pub text_display: TextDisplayConfig,
+    pub matrix_rain: MatrixRainConfig,
+    pub clock_display: ClockDisplayConfig,
+    pub bouncing_logo: BouncingLogoConfig,
+    pub system_info: SystemInfoConfig,
+    pub wave_animation: WaveAnimationConfig,
+    pub starfield: StarfieldConfig,
+}
+
+impl FeaturesConfig {
+    // Whether a feature has been switched off with `enabled = false`
+    pub fn is_enabled(&self, feature_name: &str) -> bool {
+        match feature_name {
+            "text_display" => self.text_display.enabled,
+            "matrix_rain" => self.matrix_rain.enabled,
+            "clock_display" => self.clock_display.enabled,
+            "bouncing_logo" => self.bouncing_logo.enabled,
+            "system_info" => self.system_info.enabled,
+            "wave_animation" => self.wave_animation.enabled,
+            "starfield" => self.starfield.enabled,
+            _ => false,
+        }
+    }
+}
+
+// How each line of the text_display block sits within the width
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TextAlign {
+    Left,
+    #[default]
+    Center,
+    Right,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct TextDisplayConfig {
+    pub enabled: bool,
+    pub text: Option<String>,
+    pub style: Option<String>,
+    pub align: TextAlign,
+}
+
+impl Default for TextDisplayConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            text: None,
+            style: None,
+            align: TextAlign::default(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MatrixCharset {
+    Katakana,
+    Ascii,
+    Numbers,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct MatrixRainConfig {
+    pub enabled: bool,
+    pub charset: MatrixCharset,
+    pub speed: u8,
+}
+
+impl Default for MatrixRainConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            charset: MatrixCharset::Katakana,
+            speed: 5,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ClockFormat {
+    #[serde(rename = "12hour")]
+    TwelveHour,
+    #[serde(rename = "24hour")]
+    TwentyFourHour,
+    #[serde(rename = "unix")]
+    Unix,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ClockDisplayConfig {
+    pub enabled: bool,
+    pub format: ClockFormat,
+    pub show_seconds: bool,
+}
+
+impl Default for ClockDisplayConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            format: ClockFormat::TwentyFourHour,
+            show_seconds: true,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct BouncingLogoConfig {
+    pub enabled: bool,
+    pub text: String,
+    pub speed: u8,
+}
+
+impl Default for BouncingLogoConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            text: "Terminal Screensaver".to_string(),
+            speed: 3,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SystemInfoConfig {
+    pub enabled: bool,
+    pub update_interval: u64,
+}
+
+impl Default for SystemInfoConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            update_interval: 5,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct WaveAnimationConfig {
+    pub enabled: bool,
+    pub speed: f64,
+    pub amplitude: u16,
+}
+
+impl Default for WaveAnimationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            speed: 1.0,
+            amplitude: 8,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct StarfieldConfig {
+    pub enabled: bool,
+    pub star_count: usize,
+    pub speed: f64,
+}
+
+impl Default for StarfieldConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            star_count: 100,
+            speed: 1.0,
+        }
+    }
+}
+
+pub fn parse_config(content: &str) -> Result<Config, toml::de::Error> {
+    toml::from_str(content)
+}
+
+// Load and validate a config file, logging any warnings
+pub fn load_config(path: &str) -> Result<Config, ScreensaverError> {
+    let (config, warnings) = read_config(path)?;
+    for warning in &warnings {
+        log::warn!("{}", warning);
+    }
+    Ok(config)
+}
+
+// Load and validate a config file, returning its warnings for the caller to
+// report. A missing file falls back to defaults; anything else that is wrong
+// with the file is an error, never ignored.
+pub fn read_config(path: &str) -> Result<(Config, Vec<Diagnostic>), ScreensaverError> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            let warning = Diagnostic::warning(
+                path,
+                None,
+                "config file not found, using defaults".to_string(),
+            );
+            return Ok((Config::default(), vec![warning]));
+        }
+        Err(e) => {
+            return Err(ScreensaverError::Config(vec![Diagnostic::error(
+                path,
+                None,
+                format!("cannot read config file: {}", e),
+            )]))
+        }
+    };
+
+    validate_config(&content, path).map_err(ScreensaverError::Config)
+}
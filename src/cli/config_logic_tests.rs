@@ -0,0 +1,182 @@
+#[cfg(test)]
+mod config_tests {
+    use crate::cli::config_logic::*;
+
+    #[test]
+    fn test_shipped_config_populates_every_section() {
+        let config = parse_config(include_str!("../../terminal-screensaver.toml")).unwrap();
+
+        assert_eq!(config.screensaver.default_feature, "text_display");
+        assert_eq!(config.screensaver.timeout_seconds, 60);
+        assert_eq!(
+            config.display_text(),
+            "Welcome to Terminal Screensaver v0.0.1"
+        );
+        assert_eq!(config.features.matrix_rain.charset, MatrixCharset::Katakana);
+        assert_eq!(config.features.matrix_rain.speed, 5);
+        assert_eq!(
+            config.features.clock_display.format,
+            ClockFormat::TwentyFourHour
+        );
+        assert_eq!(config.features.bouncing_logo.text, "TERMINAL SCREENSAVER");
+        assert_eq!(config.features.bouncing_logo.speed, 3);
+        assert_eq!(config.features.system_info.update_interval, 5);
+        assert_eq!(config.features.wave_animation.amplitude, 8);
+        assert_eq!(config.features.starfield.star_count, 100);
+    }
+
+    #[test]
+    fn test_demo_config_keeps_flat_layout() {
+        let config = parse_config(include_str!("../../demo-config.toml")).unwrap();
+
+        assert_eq!(
+            config.display_text(),
+            "Professional Terminal Screensaver - Script Integration Demo"
+        );
+        assert_eq!(config.actions.len(), 3);
+        assert_eq!(config.actions[0].key, "d");
+        assert!(config.features.clock_display.show_seconds);
+    }
+
+    #[test]
+    fn test_empty_config_uses_defaults() {
+        let config = parse_config("").unwrap();
+
+        assert_eq!(config.display_text(), "Welcome to Terminal Screensaver");
+        assert_eq!(config.display_style(), "default");
+        assert_eq!(config.screensaver.default_feature, "text_display");
+        assert!(config.features.starfield.enabled);
+        assert!(config.custom_actions.is_empty());
+    }
+
+    #[test]
+    fn test_terminal_settings_default_to_alternate_screen_without_mouse() {
+        let config = parse_config("").unwrap();
+        assert!(config.screensaver.alternate_screen);
+        assert!(!config.screensaver.mouse_capture);
+
+        let config =
+            parse_config("[screensaver]\nalternate_screen = false\nmouse_capture = true\n")
+                .unwrap();
+        assert!(!config.screensaver.alternate_screen);
+        assert!(config.screensaver.mouse_capture);
+    }
+
+    #[test]
+    fn test_logging_section_is_parsed() {
+        let config = parse_config("").unwrap();
+        assert_eq!(config.logging.level, LogLevel::Info);
+        assert_eq!(config.logging.file, None);
+        assert_eq!(config.logging.format, LogFormat::Timestamped);
+
+        let config =
+            parse_config("[logging]\nlevel = \"debug\"\nfile = \"none\"\nformat = \"plain\"\n")
+                .unwrap();
+        assert_eq!(config.logging.level, LogLevel::Debug);
+        assert_eq!(config.logging.level.filter(), log::LevelFilter::Debug);
+        assert_eq!(config.logging.file.as_deref(), Some("none"));
+        assert_eq!(config.logging.format, LogFormat::Plain);
+
+        assert!(parse_config("[logging]\nlevel = \"loud\"\n").is_err());
+    }
+
+    #[test]
+    fn test_feature_section_overrides_top_level_text() {
+        let config = parse_config(
+            r#"
+            text = "legacy"
+            style = "red"
+
+            [features.text_display]
+            text = "from feature"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.display_text(), "from feature");
+        assert_eq!(config.display_style(), "red");
+    }
+
+    #[test]
+    fn test_text_display_takes_multi_line_text_and_alignment() {
+        let config = parse_config(
+            "[features.text_display]\ntext = \"\"\"\nBuild farm\nstatus board\"\"\"\nalign = \"right\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.display_text(), "Build farm\nstatus board");
+        assert_eq!(config.features.text_display.align, TextAlign::Right);
+        assert_eq!(
+            Config::default().features.text_display.align,
+            TextAlign::Center
+        );
+    }
+
+    #[test]
+    fn test_custom_actions_are_collected() {
+        let config = parse_config(
+            r#"
+            [custom_actions]
+            deploy = "./deploy.sh"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.custom_actions.get("deploy").map(String::as_str),
+            Some("./deploy.sh")
+        );
+    }
+
+    #[test]
+    fn test_structured_actions_are_parsed() {
+        let config = parse_config(
+            r#"
+            [[actions]]
+            key = "s"
+            description = "Status"
+            argv = ["git", "status", "--short"]
+            cwd = "/srv/app"
+            env = { GIT_PAGER = "cat" }
+            timeout_seconds = 10
+            confirm = true
+            pause = false
+            output = "pane"
+
+            [[actions]]
+            key = "d"
+            description = "Date"
+            command = "date"
+            "#,
+        )
+        .unwrap();
+
+        let status = &config.actions[0];
+        assert_eq!(status.argv, ["git", "status", "--short"]);
+        assert_eq!(status.cwd.as_deref(), Some("/srv/app"));
+        assert_eq!(status.env.get("GIT_PAGER").map(String::as_str), Some("cat"));
+        assert_eq!(status.timeout(), Some(std::time::Duration::from_secs(10)));
+        assert!(status.confirm);
+        assert!(!status.pause);
+        assert_eq!(status.output, ActionOutput::Pane);
+
+        let date = &config.actions[1];
+        assert_eq!(date.command, "date");
+        assert!(date.argv.is_empty());
+        assert!(!date.confirm);
+        assert!(date.pause);
+        assert_eq!(date.output, ActionOutput::Terminal);
+    }
+
+    #[test]
+    fn test_unknown_clock_format_is_rejected() {
+        let result = parse_config(
+            r#"
+            [features.clock_display]
+            format = "48hour"
+            "#,
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -1 +1,10 @@
 pub mod cli_logic;
+pub mod config_logic;
+pub mod validation_logic;
+
+#[cfg(test)]
+pub mod cli_logic_tests;
+#[cfg(test)]
+pub mod config_logic_tests;
+#[cfg(test)]
+pub mod validation_logic_tests;
@@ -0,0 +1,541 @@
+use super::config_logic::{parse_config, Config};
+use crate::features::get_available_features;
+use crate::styles::theme_logic::{parse_attribute, parse_color, BUNDLED_THEMES};
+use std::fmt;
+use toml::de::{DeTable, DeValue};
+use toml::Spanned;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+// A single finding from the validation pass, pointing back into the config file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub file: String,
+    pub location: Option<(usize, usize)>,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn error(file: &str, location: Option<(usize, usize)>, message: String) -> Self {
+        Self {
+            severity: Severity::Error,
+            file: file.to_string(),
+            location,
+            message,
+        }
+    }
+
+    pub fn warning(file: &str, location: Option<(usize, usize)>, message: String) -> Self {
+        Self {
+            severity: Severity::Warning,
+            file: file.to_string(),
+            location,
+            message,
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        match self.location {
+            Some((line, column)) => write!(
+                f,
+                "{}:{}:{}: {}: {}",
+                self.file, line, column, severity, self.message
+            ),
+            None => write!(f, "{}: {}: {}", self.file, severity, self.message),
+        }
+    }
+}
+
+enum Range {
+    Integer(i64, i64),
+    Float(f64, f64),
+}
+
+const ROOT_KEYS: &[&str] = &[
+    "text",
+    "style",
+    "screensaver",
+    "logging",
+    "themes",
+    "features",
+    "playlist",
+    "custom_actions",
+    "actions",
+];
+const SCREENSAVER_KEYS: &[&str] = &[
+    "default_feature",
+    "timeout_seconds",
+    "alternate_screen",
+    "mouse_capture",
+    "theme",
+];
+const LOGGING_KEYS: &[&str] = &["level", "file", "format"];
+const THEME_KEYS: &[&str] = &[
+    "base",
+    "primary",
+    "accent",
+    "help_border",
+    "help_text",
+    "dim",
+    "success",
+    "warning",
+    "error",
+    "background",
+    "palette",
+];
+const THEME_ROLES: &[&str] = &[
+    "primary",
+    "accent",
+    "help_border",
+    "help_text",
+    "dim",
+    "success",
+    "warning",
+    "error",
+];
+const ROLE_KEYS: &[&str] = &["fg", "bg", "attributes"];
+const ACTION_KEYS: &[&str] = &[
+    "key",
+    "description",
+    "command",
+    "argv",
+    "cwd",
+    "env",
+    "timeout_seconds",
+    "confirm",
+    "pause",
+    "output",
+];
+const PLAYLIST_KEYS: &[&str] = &["order", "transition", "default_duration_seconds", "entries"];
+const PLAYLIST_ENTRY_KEYS: &[&str] = &["feature", "duration_seconds"];
+
+fn feature_keys(feature: &str) -> Option<&'static [&'static str]> {
+    match feature {
+        "text_display" => Some(&["enabled", "text", "style", "align"]),
+        "matrix_rain" => Some(&["enabled", "charset", "speed"]),
+        "clock_display" => Some(&["enabled", "format", "show_seconds"]),
+        "bouncing_logo" => Some(&["enabled", "text", "speed"]),
+        "system_info" => Some(&["enabled", "update_interval"]),
+        "wave_animation" => Some(&["enabled", "speed", "amplitude"]),
+        "starfield" => Some(&["enabled", "star_count", "speed"]),
+        _ => None,
+    }
+}
+
+// Ranges documented in terminal-screensaver.toml
+fn value_range(section: &str, key: &str) -> Option<Range> {
+    match (section, key) {
+        ("screensaver", "timeout_seconds") => Some(Range::Integer(0, 86400)),
+        ("playlist", "default_duration_seconds") => Some(Range::Integer(1, 86400)),
+        ("playlist.entries", "duration_seconds") => Some(Range::Integer(1, 86400)),
+        ("actions", "timeout_seconds") => Some(Range::Integer(1, 86400)),
+        ("matrix_rain", "speed") => Some(Range::Integer(1, 10)),
+        ("bouncing_logo", "speed") => Some(Range::Integer(1, 10)),
+        ("wave_animation", "speed") => Some(Range::Float(0.1, 5.0)),
+        ("wave_animation", "amplitude") => Some(Range::Integer(1, 15)),
+        ("starfield", "star_count") => Some(Range::Integer(25, 200)),
+        ("starfield", "speed") => Some(Range::Float(0.1, 5.0)),
+        _ => None,
+    }
+}
+
+// Validate config file contents. On success the parsed config is returned
+// together with any warnings; otherwise every diagnostic found is returned.
+pub fn validate_config(
+    content: &str,
+    file: &str,
+) -> Result<(Config, Vec<Diagnostic>), Vec<Diagnostic>> {
+    let validator = Validator { content, file };
+
+    let document = match DeTable::parse(content) {
+        Ok(document) => document,
+        Err(e) => {
+            return Err(vec![Diagnostic::error(
+                file,
+                e.span().map(|span| validator.location(span.start)),
+                e.message().trim().to_string(),
+            )])
+        }
+    };
+
+    let mut diagnostics = Vec::new();
+    validator.check_root(document.get_ref(), &mut diagnostics);
+
+    let config = match parse_config(content) {
+        Ok(config) => Some(config),
+        Err(e) => {
+            diagnostics.push(Diagnostic::error(
+                file,
+                e.span().map(|span| validator.location(span.start)),
+                e.message().trim().to_string(),
+            ));
+            None
+        }
+    };
+
+    diagnostics.sort_by_key(|d| d.location);
+
+    match config {
+        Some(config) if !diagnostics.iter().any(Diagnostic::is_error) => Ok((config, diagnostics)),
+        _ => Err(diagnostics),
+    }
+}
+
+struct Validator<'a> {
+    content: &'a str,
+    file: &'a str,
+}
+
+impl Validator<'_> {
+    // 1-based line and column (in characters) of a byte offset
+    fn location(&self, offset: usize) -> (usize, usize) {
+        let before = &self.content[..offset.min(self.content.len())];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let column = before[line_start..].chars().count() + 1;
+        (line, column)
+    }
+
+    fn error<T>(&self, at: &Spanned<T>, message: String) -> Diagnostic {
+        Diagnostic::error(self.file, Some(self.location(at.span().start)), message)
+    }
+
+    fn warning<T>(&self, at: &Spanned<T>, message: String) -> Diagnostic {
+        Diagnostic::warning(self.file, Some(self.location(at.span().start)), message)
+    }
+
+    fn check_unknown_keys(
+        &self,
+        table: &DeTable<'_>,
+        known: &[&str],
+        section: &str,
+        out: &mut Vec<Diagnostic>,
+    ) {
+        for key in table.keys() {
+            if !known.contains(&key.get_ref().as_ref()) {
+                out.push(self.warning(
+                    key,
+                    format!("unknown key `{}` in {}", key.get_ref(), section),
+                ));
+            }
+        }
+    }
+
+    fn check_root(&self, root: &DeTable<'_>, out: &mut Vec<Diagnostic>) {
+        self.check_unknown_keys(root, ROOT_KEYS, "top-level table", out);
+        self.check_theme_name(root, out);
+
+        for (key, value) in root {
+            match (key.get_ref().as_ref(), value.get_ref()) {
+                ("screensaver", DeValue::Table(table)) => self.check_screensaver(table, out),
+                ("logging", DeValue::Table(table)) => {
+                    self.check_unknown_keys(table, LOGGING_KEYS, "[logging]", out)
+                }
+                ("themes", DeValue::Table(table)) => self.check_themes(table, out),
+                ("features", DeValue::Table(table)) => self.check_features(table, out),
+                ("playlist", DeValue::Table(table)) => self.check_playlist(table, out),
+                ("actions", DeValue::Array(actions)) => self.check_actions(actions.iter(), out),
+                _ => {}
+            }
+        }
+    }
+
+    fn check_screensaver(&self, table: &DeTable<'_>, out: &mut Vec<Diagnostic>) {
+        self.check_unknown_keys(table, SCREENSAVER_KEYS, "[screensaver]", out);
+        self.check_ranges("screensaver", table, out);
+
+        if let Some(value) = table.get("default_feature") {
+            self.check_feature_name(value, out);
+        }
+    }
+
+    // `[screensaver] theme` names a bundled theme or one under [themes]
+    fn check_theme_name(&self, root: &DeTable<'_>, out: &mut Vec<Diagnostic>) {
+        let Some(DeValue::Table(screensaver)) = root.get("screensaver").map(|v| v.get_ref()) else {
+            return;
+        };
+        let Some(value) = screensaver.get("theme") else {
+            return;
+        };
+        let DeValue::String(name) = value.get_ref() else {
+            return;
+        };
+        let defined = match root.get("themes").map(|v| v.get_ref()) {
+            Some(DeValue::Table(themes)) => themes.contains_key(name.as_ref()),
+            _ => false,
+        };
+        if !defined && !BUNDLED_THEMES.contains(&name.as_ref()) {
+            out.push(self.error(
+                value,
+                format!(
+                    "unknown theme `{}` (bundled: {}; or define it under [themes.{}])",
+                    name,
+                    BUNDLED_THEMES.join(", "),
+                    name
+                ),
+            ));
+        }
+    }
+
+    fn check_themes(&self, themes: &DeTable<'_>, out: &mut Vec<Diagnostic>) {
+        for (name, theme) in themes {
+            let DeValue::Table(theme) = theme.get_ref() else {
+                continue;
+            };
+            let section = format!("[themes.{}]", name.get_ref());
+            self.check_unknown_keys(theme, THEME_KEYS, &section, out);
+
+            for (key, value) in theme {
+                match (key.get_ref().as_ref(), value.get_ref()) {
+                    ("base", DeValue::String(base)) if !BUNDLED_THEMES.contains(&base.as_ref()) => {
+                        out.push(self.error(
+                            value,
+                            format!(
+                                "unknown base theme `{}` (bundled: {})",
+                                base,
+                                BUNDLED_THEMES.join(", ")
+                            ),
+                        ))
+                    }
+                    ("background", _) => self.check_color(value, out),
+                    ("palette", DeValue::Array(colors)) => {
+                        colors.iter().for_each(|color| self.check_color(color, out))
+                    }
+                    (role, DeValue::String(_)) if THEME_ROLES.contains(&role) => {
+                        self.check_color(value, out)
+                    }
+                    (role, DeValue::Table(style)) if THEME_ROLES.contains(&role) => {
+                        let section = format!("{} {}", section, role);
+                        self.check_unknown_keys(style, ROLE_KEYS, &section, out);
+                        self.check_role_style(style, out);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn check_role_style(&self, style: &DeTable<'_>, out: &mut Vec<Diagnostic>) {
+        for key in ["fg", "bg"] {
+            if let Some(value) = style.get(key) {
+                self.check_color(value, out);
+            }
+        }
+        let Some(DeValue::Array(attributes)) = style.get("attributes").map(|v| v.get_ref()) else {
+            return;
+        };
+        for attribute in attributes.iter() {
+            if let DeValue::String(name) = attribute.get_ref() {
+                if parse_attribute(name).is_none() {
+                    out.push(self.error(
+                        attribute,
+                        format!(
+                            "unknown attribute `{}` (available: bold, dim, italic, underline, reverse)",
+                            name
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+
+    fn check_color(&self, value: &Spanned<DeValue<'_>>, out: &mut Vec<Diagnostic>) {
+        if let DeValue::String(name) = value.get_ref() {
+            if parse_color(name).is_none() {
+                out.push(self.error(
+                    value,
+                    format!(
+                        "unknown color `{}` (a color name, `#rrggbb` or a 0-255 palette index)",
+                        name
+                    ),
+                ));
+            }
+        }
+    }
+
+    fn check_feature_name(&self, value: &Spanned<DeValue<'_>>, out: &mut Vec<Diagnostic>) {
+        if let DeValue::String(name) = value.get_ref() {
+            let available = get_available_features();
+            if !available.contains(&name.as_ref()) {
+                out.push(self.error(
+                    value,
+                    format!(
+                        "unknown feature `{}` (available: {})",
+                        name,
+                        available.join(", ")
+                    ),
+                ));
+            }
+        }
+    }
+
+    fn check_playlist(&self, table: &DeTable<'_>, out: &mut Vec<Diagnostic>) {
+        self.check_unknown_keys(table, PLAYLIST_KEYS, "[playlist]", out);
+        self.check_ranges("playlist", table, out);
+
+        let Some(entries) = table.get("entries") else {
+            return;
+        };
+        let DeValue::Array(entries) = entries.get_ref() else {
+            return;
+        };
+        for entry in entries.iter() {
+            let DeValue::Table(entry) = entry.get_ref() else {
+                continue;
+            };
+            self.check_unknown_keys(entry, PLAYLIST_ENTRY_KEYS, "[[playlist.entries]]", out);
+            self.check_ranges("playlist.entries", entry, out);
+            if let Some(value) = entry.get("feature") {
+                self.check_feature_name(value, out);
+            }
+        }
+    }
+
+    fn check_features(&self, features: &DeTable<'_>, out: &mut Vec<Diagnostic>) {
+        for (name, value) in features {
+            let Some(known) = feature_keys(name.get_ref()) else {
+                out.push(self.warning(
+                    name,
+                    format!("unknown feature section `{}`", name.get_ref()),
+                ));
+                continue;
+            };
+            if let DeValue::Table(table) = value.get_ref() {
+                let section = format!("[features.{}]", name.get_ref());
+                self.check_unknown_keys(table, known, &section, out);
+                self.check_ranges(name.get_ref(), table, out);
+            }
+        }
+    }
+
+    fn check_ranges(&self, section: &str, table: &DeTable<'_>, out: &mut Vec<Diagnostic>) {
+        for (key, value) in table {
+            let Some(range) = value_range(section, key.get_ref()) else {
+                continue;
+            };
+            // Type mismatches are reported by the typed parse instead
+            let Some(number) = numeric_value(value.get_ref()) else {
+                continue;
+            };
+            let (in_range, min, max) = match range {
+                Range::Integer(min, max) => (
+                    number >= min as f64 && number <= max as f64,
+                    min.to_string(),
+                    max.to_string(),
+                ),
+                Range::Float(min, max) => (
+                    number >= min && number <= max,
+                    min.to_string(),
+                    max.to_string(),
+                ),
+            };
+            if !in_range {
+                out.push(self.error(
+                    value,
+                    format!(
+                        "`{}` is {} but must be between {} and {}",
+                        key.get_ref(),
+                        number,
+                        min,
+                        max
+                    ),
+                ));
+            }
+        }
+    }
+
+    fn check_actions<'v, 'i: 'v>(
+        &self,
+        actions: impl Iterator<Item = &'v Spanned<DeValue<'i>>>,
+        out: &mut Vec<Diagnostic>,
+    ) {
+        let mut seen: Vec<(String, (usize, usize))> = Vec::new();
+
+        for action in actions {
+            let DeValue::Table(table) = action.get_ref() else {
+                continue;
+            };
+            self.check_unknown_keys(table, ACTION_KEYS, "[[actions]]", out);
+            self.check_ranges("actions", table, out);
+            self.check_action_command(action, table, out);
+
+            let Some(key) = table.get("key") else {
+                continue;
+            };
+            let DeValue::String(name) = key.get_ref() else {
+                continue;
+            };
+            // Keys are matched case-insensitively when dispatching
+            let normalized = name.to_lowercase();
+            let location = self.location(key.span().start);
+            if let Some((_, (line, column))) = seen.iter().find(|(k, _)| *k == normalized) {
+                out.push(self.error(
+                    key,
+                    format!(
+                        "duplicate action key `{}` (first defined at line {}, column {})",
+                        name, line, column
+                    ),
+                ));
+            } else {
+                seen.push((normalized, location));
+            }
+        }
+    }
+
+    // An action runs either a shell `command` or an `argv` list, never both
+    fn check_action_command(
+        &self,
+        action: &Spanned<DeValue<'_>>,
+        table: &DeTable<'_>,
+        out: &mut Vec<Diagnostic>,
+    ) {
+        let command = table.get("command");
+        let argv = table.get("argv");
+
+        match (command, argv) {
+            (Some(command), Some(_)) => out.push(self.error(
+                command,
+                "action sets both `command` and `argv`; use one of them".to_string(),
+            )),
+            (None, None) => out.push(self.error(
+                action,
+                "action needs either `command` or `argv`".to_string(),
+            )),
+            (None, Some(argv)) => {
+                if let DeValue::Array(items) = argv.get_ref() {
+                    if items.is_empty() {
+                        out.push(
+                            self.error(argv, "`argv` must name the program to run".to_string()),
+                        );
+                    }
+                }
+            }
+            (Some(_), None) => {}
+        }
+    }
+}
+
+fn numeric_value(value: &DeValue<'_>) -> Option<f64> {
+    match value {
+        DeValue::Integer(integer) => i64::from_str_radix(integer.as_str(), integer.radix())
+            .ok()
+            .map(|n| n as f64),
+        DeValue::Float(float) => float.as_str().replace('_', "").parse().ok(),
+        _ => None,
+    }
+}
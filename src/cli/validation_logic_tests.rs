@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod validation_tests {
+    use crate::cli::validation_logic::*;
+
+    fn errors(content: &str) -> Vec<Diagnostic> {
+        validate_config(content, "test.toml").unwrap_err()
+    }
+
+    #[test]
+    fn test_shipped_configs_are_valid() {
+        let (_, warnings) = validate_config(
+            include_str!("../../terminal-screensaver.toml"),
+            "terminal-screensaver.toml",
+        )
+        .unwrap();
+        assert!(warnings.is_empty(), "{:?}", warnings);
+
+        let (_, warnings) =
+            validate_config(include_str!("../../demo-config.toml"), "demo-config.toml").unwrap();
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_unknown_key_is_a_warning_with_location() {
+        let (config, warnings) = validate_config(
+            "text = \"hi\"\n\n[features.starfield]\ncount = 5\n",
+            "test.toml",
+        )
+        .unwrap();
+
+        assert_eq!(config.text, "hi");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].severity, Severity::Warning);
+        assert_eq!(warnings[0].location, Some((4, 1)));
+        assert_eq!(
+            warnings[0].to_string(),
+            "test.toml:4:1: warning: unknown key `count` in [features.starfield]"
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_values_are_errors() {
+        let diagnostics = errors(
+            "[screensaver]\ntimeout_seconds = 90000\n\n[features.matrix_rain]\nspeed = 11\n\n[features.wave_animation]\nspeed = 7.5\n",
+        );
+
+        assert_eq!(diagnostics.len(), 3);
+        assert!(diagnostics.iter().all(Diagnostic::is_error));
+        assert_eq!(diagnostics[0].location, Some((2, 19)));
+        assert!(diagnostics[0].message.contains("between 0 and 86400"));
+        assert_eq!(diagnostics[1].location, Some((5, 9)));
+        assert_eq!(diagnostics[2].location, Some((8, 9)));
+    }
+
+    #[test]
+    fn test_values_on_range_boundaries_are_accepted() {
+        let result = validate_config(
+            "[features.starfield]\nstar_count = 25\nspeed = 5.0\n\n[features.bouncing_logo]\nspeed = 10\n",
+            "test.toml",
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_duplicate_action_keys_point_at_second_definition() {
+        let diagnostics = errors(
+            "[[actions]]\nkey = \"d\"\ndescription = \"one\"\ncommand = \"true\"\n\n[[actions]]\nkey = \"D\"\ndescription = \"two\"\ncommand = \"true\"\n",
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].location, Some((7, 7)));
+        assert!(diagnostics[0].message.contains("line 2, column 7"));
+    }
+
+    #[test]
+    fn test_unknown_default_feature_is_an_error() {
+        let diagnostics = errors("[screensaver]\ndefault_feature = \"screen_melt\"\n");
+
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("screen_melt"));
+    }
+
+    #[test]
+    fn test_syntax_and_type_errors_have_locations() {
+        let diagnostics = errors("text = \"unterminated\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].location.map(|(line, _)| line), Some(1));
+
+        let diagnostics = errors("[features.starfield]\nspeed = \"fast\"\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].location, Some((2, 9)));
+    }
+
+    #[test]
+    fn test_playlist_entries_are_checked() {
+        let diagnostics = errors(
+            "[playlist]
+default_duration_seconds = 0
+
+[[playlist.entries]]
+feature = \"fireworks\"
+duration_seconds = 30
+",
+        );
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].location, Some((2, 28)));
+        assert!(diagnostics[0].message.contains("between 1 and 86400"));
+        assert_eq!(diagnostics[1].location, Some((5, 11)));
+        assert!(diagnostics[1]
+            .message
+            .contains("unknown feature `fireworks`"));
+    }
+
+    #[test]
+    fn test_action_needs_exactly_one_of_command_and_argv() {
+        let both = errors(
+            "[[actions]]\nkey = \"d\"\ndescription = \"Deploy\"\ncommand = \"./deploy.sh\"\nargv = [\"./deploy.sh\"]\n",
+        );
+        assert_eq!(both.len(), 1);
+        assert_eq!(both[0].location, Some((4, 11)));
+        assert!(both[0].message.contains("both `command` and `argv`"));
+
+        let neither = errors("[[actions]]\nkey = \"d\"\ndescription = \"Deploy\"\n");
+        assert_eq!(neither.len(), 1);
+        assert!(neither[0].message.contains("either `command` or `argv`"));
+
+        let empty = errors("[[actions]]\nkey = \"d\"\ndescription = \"Deploy\"\nargv = []\n");
+        assert_eq!(empty.len(), 1);
+        assert!(empty[0].message.contains("`argv` must name the program"));
+    }
+
+    #[test]
+    fn test_action_timeout_is_range_checked() {
+        let diagnostics = errors(
+            "[[actions]]\nkey = \"s\"\ndescription = \"Status\"\nargv = [\"git\", \"status\"]\ntimeout_seconds = 0\n",
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].location, Some((5, 19)));
+    }
+
+    #[test]
+    fn test_theme_names_are_checked() {
+        assert!(validate_config("[screensaver]\ntheme = \"nord\"\n", "test.toml").is_ok());
+        assert!(validate_config(
+            "[screensaver]\ntheme = \"mine\"\n\n[themes.mine]\nbase = \"gruvbox\"\n",
+            "test.toml"
+        )
+        .is_ok());
+
+        let diagnostics = errors("[screensaver]\ntheme = \"neon\"\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("unknown theme `neon`"));
+
+        let diagnostics = errors("[themes.mine]\nbase = \"neon\"\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("unknown base theme"));
+    }
+
+    #[test]
+    fn test_theme_colors_and_attributes_are_checked() {
+        let diagnostics = errors(
+            "[themes.mine]\naccent = \"chartreuse\"\npalette = [\"red\", \"teal\"]\n\n[themes.mine.dim]\nfg = \"grey\"\nattributes = [\"blink\"]\n",
+        );
+
+        let messages: Vec<&str> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(messages.len(), 3, "{:?}", messages);
+        assert!(messages[0].contains("unknown color `chartreuse`"));
+        assert!(messages[1].contains("unknown color `teal`"));
+        assert!(messages[2].contains("unknown attribute `blink`"));
+        assert_eq!(diagnostics[0].location, Some((2, 10)));
+    }
+}
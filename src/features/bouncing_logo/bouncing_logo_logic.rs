@@ -1,6 +1,10 @@
-use crate::shared::{Rect, TextLine};
+use crate::cli::config_logic::BouncingLogoConfig;
+use crate::features::{frame_steps, ScreensaverAction, ScreensaverFeature};
+use crate::shared::text_width::{center_text, display_width};
+use crate::shared::{Rect, SeededRng, TextLine};
+use crate::styles::theme_logic::Theme;
 use crossterm::event::{KeyCode, KeyEvent};
-use crossterm::style::Color;
+use std::time::Duration;
 
 pub struct BouncingLogoFeature {
     text: String,
@@ -8,7 +12,9 @@ pub struct BouncingLogoFeature {
     y: f64,
     velocity_x: f64,
     velocity_y: f64,
-    color: Color,
+    // Index into the theme's palette
+    color: usize,
+    theme: Theme,
 }
 
 impl Default for BouncingLogoFeature {
@@ -25,13 +31,72 @@ impl BouncingLogoFeature {
             y: 5.0,
             velocity_x: 1.0,
             velocity_y: 0.5,
-            color: Color::Green,
+            color: 0,
+            theme: Theme::default(),
         }
     }
 
-    pub fn render(&mut self, area: Rect) -> Vec<TextLine> {
-        // Update position
-        self.update_position(area.clone());
+    pub fn with_config(config: &BouncingLogoConfig) -> Self {
+        // Config speed 1-10, where the default of 3 matches the built-in velocity
+        let scale = config.speed.clamp(1, 10) as f64 / 3.0;
+        Self {
+            text: config.text.clone(),
+            velocity_x: scale,
+            velocity_y: 0.5 * scale,
+            ..Self::new()
+        }
+    }
+
+    // Random starting direction and color, drawn from `rng`
+    pub fn with_rng(mut self, mut rng: SeededRng) -> Self {
+        if rng.range(0, 1) == 1 {
+            self.velocity_x = -self.velocity_x;
+        }
+        if rng.range(0, 1) == 1 {
+            self.velocity_y = -self.velocity_y;
+        }
+        self.color = rng.index(self.theme.palette.len().max(1));
+        self
+    }
+
+    // The logo cycles through the theme's palette
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    // Columns the logo takes on screen
+    fn text_width(&self) -> f64 {
+        display_width(&self.text) as f64
+    }
+
+    // Bounce off the edges of `area` after moving
+    fn bounce(&mut self, area: Rect) {
+        let text_width = self.text_width();
+
+        if self.x <= 0.0 || self.x + text_width >= area.width as f64 {
+            self.velocity_x = -self.velocity_x;
+            self.x = self.x.max(0.0).min(area.width as f64 - text_width);
+        }
+
+        let bottom = logo_rows(&area).saturating_sub(1) as f64;
+        if self.y <= 0.0 || self.y >= bottom {
+            self.velocity_y = -self.velocity_y;
+            self.y = self.y.max(0.0).min(bottom);
+        }
+    }
+}
+
+impl ScreensaverFeature for BouncingLogoFeature {
+    fn tick(&mut self, dt: Duration) {
+        // Velocities are in cells per reference frame
+        let steps = frame_steps(dt);
+        self.x += self.velocity_x * steps;
+        self.y += self.velocity_y * steps;
+    }
+
+    fn render(&mut self, area: Rect) -> Vec<TextLine> {
+        self.bounce(area.clone());
 
         let mut lines = Vec::new();
 
@@ -39,12 +104,15 @@ impl BouncingLogoFeature {
         for y in 0..area.height {
             if y == self.y as u16
                 && self.x >= 0.0
-                && (self.x as usize + self.text.len()) <= area.width as usize
+                && self.x + self.text_width() <= area.width as f64
             {
                 // Create line with logo at the correct position
                 let padding = " ".repeat(self.x as usize);
                 let line_content = format!("{}{}", padding, self.text);
-                lines.push(TextLine::with_color(line_content, self.color));
+                lines.push(TextLine::with_color(
+                    line_content,
+                    self.theme.palette_color(self.color),
+                ));
             } else {
                 lines.push(TextLine::new(String::new()));
             }
@@ -53,16 +121,10 @@ impl BouncingLogoFeature {
         // Add controls at bottom if there's space
         if area.height > 2 {
             let controls = "Press ESC to exit | SPACE to change color";
-            let padding = if controls.len() < area.width as usize {
-                (area.width as usize - controls.len()) / 2
-            } else {
-                0
-            };
-
             if let Some(last_line) = lines.last_mut() {
-                *last_line = TextLine::with_color(
-                    format!("{}{}", " ".repeat(padding), controls),
-                    Color::DarkGrey,
+                *last_line = TextLine::styled(
+                    center_text(controls, area.width as usize),
+                    self.theme.dim.clone(),
                 );
             }
         }
@@ -70,21 +132,13 @@ impl BouncingLogoFeature {
         lines
     }
 
-    pub fn handle_input(&mut self, key_event: KeyEvent) -> ScreensaverAction {
+    fn handle_input(&mut self, key_event: KeyEvent) -> ScreensaverAction {
         match key_event.code {
             KeyCode::Esc => ScreensaverAction::Exit,
             KeyCode::Enter => ScreensaverAction::NextScreen,
             KeyCode::Char(' ') => {
-                // Change color
-                self.color = match self.color {
-                    Color::Green => Color::Cyan,
-                    Color::Cyan => Color::Yellow,
-                    Color::Yellow => Color::Magenta,
-                    Color::Magenta => Color::Red,
-                    Color::Red => Color::Blue,
-                    Color::Blue => Color::White,
-                    _ => Color::Green,
-                };
+                // Next palette color
+                self.color = (self.color + 1) % self.theme.palette.len().max(1);
                 ScreensaverAction::Continue
             }
             KeyCode::Char('+') | KeyCode::Char('=') => {
@@ -103,39 +157,27 @@ impl BouncingLogoFeature {
         }
     }
 
-    pub fn resize(&mut self, new_area: Rect) {
+    fn resize(&mut self, new_area: Rect) {
         // Adjust position if outside new boundaries
-        if self.x + self.text.len() as f64 >= new_area.width as f64 {
-            self.x = (new_area.width as f64 - self.text.len() as f64).max(0.0);
+        if self.x + self.text_width() >= new_area.width as f64 {
+            self.x = (new_area.width as f64 - self.text_width()).max(0.0);
         }
-        if self.y >= new_area.height as f64 {
-            self.y = (new_area.height as f64 - 1.0).max(0.0);
+        let rows = logo_rows(&new_area) as f64;
+        if self.y >= rows {
+            self.y = (rows - 1.0).max(0.0);
         }
     }
 
-    fn update_position(&mut self, area: Rect) {
-        // Update position
-        self.x += self.velocity_x;
-        self.y += self.velocity_y;
-
-        // Bounce off edges
-        let text_width = self.text.len() as f64;
-
-        if self.x <= 0.0 || self.x + text_width >= area.width as f64 {
-            self.velocity_x = -self.velocity_x;
-            self.x = self.x.max(0.0).min(area.width as f64 - text_width);
-        }
-
-        if self.y <= 0.0 || self.y >= area.height as f64 - 1.0 {
-            self.velocity_y = -self.velocity_y;
-            self.y = self.y.max(0.0).min(area.height as f64 - 1.0);
-        }
+    fn name(&self) -> &'static str {
+        "bouncing_logo"
     }
 }
 
-#[derive(Debug, PartialEq)]
-pub enum ScreensaverAction {
-    Continue,
-    Exit,
-    NextScreen,
+// Rows the logo may use; the bottom row is kept for the controls when shown
+fn logo_rows(area: &Rect) -> u16 {
+    if area.height > 2 {
+        area.height - 1
+    } else {
+        area.height
+    }
 }
@@ -1,17 +1,121 @@
 #[cfg(test)]
 mod bouncing_logo_tests {
-    use super::*;
+    use crate::cli::config_logic::BouncingLogoConfig;
+    use crate::features::bouncing_logo::bouncing_logo_logic::BouncingLogoFeature;
+    use crate::features::{ScreensaverAction, ScreensaverFeature};
+    use crate::shared::snapshot::{assert_snapshot, render_frames, screen_text, snapshot_frames};
+    use crate::shared::Rect;
+    use crossterm::event::{KeyCode, KeyEvent};
+    use std::time::Duration;
+
+    fn logo_row(screen: &str) -> Option<(usize, usize)> {
+        screen
+            .lines()
+            .enumerate()
+            .find_map(|(y, line)| line.find("LOGO").map(|x| (x, y)))
+    }
+
+    fn logo(text: &str) -> BouncingLogoFeature {
+        BouncingLogoFeature::with_config(&BouncingLogoConfig {
+            enabled: true,
+            text: text.to_string(),
+            speed: 3,
+        })
+    }
+
+    #[test]
+    fn test_bouncing_logo_snapshot() {
+        let mut feature = BouncingLogoFeature::new();
+        let snapshot = snapshot_frames(&mut feature, Rect::new(0, 0, 50, 8), 3);
+
+        assert_snapshot("bouncing_logo_50x8", &snapshot);
+    }
+
+    #[test]
+    fn test_logo_moves_diagonally() {
+        let mut feature = logo("LOGO");
+        let frames = render_frames(&mut feature, Rect::new(0, 0, 40, 12), 3);
+
+        let first = logo_row(&screen_text(&frames[0])).unwrap();
+        let last = logo_row(&screen_text(&frames[2])).unwrap();
+        assert_eq!(last.0, first.0 + 2);
+        assert_eq!(last.1, first.1 + 1);
+    }
+
+    #[test]
+    fn test_logo_stays_inside_the_area() {
+        let mut feature = logo("LOGO");
+        let area = Rect::new(0, 0, 12, 5);
+
+        for screen in render_frames(&mut feature, area, 60) {
+            let text = screen_text(&screen);
+            let (x, y) = logo_row(&text).expect("logo is always visible");
+            assert!(x + 4 <= 12);
+            assert!(y < 4, "logo overlapped the controls row");
+        }
+    }
 
     #[test]
-    fn test_bouncing_logo_creation() {
-        // Basic test to ensure compilation
-        assert_eq!(2 + 2, 4);
+    fn test_wide_logo_bounces_off_its_display_width() {
+        // Two Japanese characters, four columns wide
+        let mut feature = logo("\u{65E5}\u{672C}");
+        let area = Rect::new(0, 0, 12, 5);
+
+        let right_edges: Vec<u16> = render_frames(&mut feature, area, 60)
+            .iter()
+            .filter_map(|screen| {
+                (0..screen.height)
+                    .flat_map(|y| (0..screen.width).map(move |x| (x, y)))
+                    .find(|&(x, y)| screen.cell(x, y).unwrap().symbol == "\u{65E5}")
+                    .map(|(x, _)| x + 4)
+            })
+            .collect();
+        assert_eq!(right_edges.len(), 60, "logo is always visible");
+        assert_eq!(right_edges.iter().max(), Some(&12));
     }
 
     #[test]
-    fn test_placeholder_functionality() {
-        // Placeholder test until we implement the proper bouncing logo feature
-        let result = true;
-        assert!(result);
+    fn test_resize_pulls_logo_back_on_screen() {
+        let mut feature = logo("LOGO");
+        render_frames(&mut feature, Rect::new(0, 0, 80, 24), 1);
+
+        let frames = render_frames(&mut feature, Rect::new(0, 0, 8, 3), 1);
+        assert!(logo_row(&screen_text(&frames[0])).is_some());
+    }
+
+    #[test]
+    fn test_handle_keys() {
+        let mut feature = BouncingLogoFeature::new();
+
+        assert_eq!(
+            feature.handle_input(KeyEvent::from(KeyCode::Esc)),
+            ScreensaverAction::Exit
+        );
+        assert_eq!(
+            feature.handle_input(KeyEvent::from(KeyCode::Enter)),
+            ScreensaverAction::NextScreen
+        );
+        assert_eq!(
+            feature.handle_input(KeyEvent::from(KeyCode::Char(' '))),
+            ScreensaverAction::Continue
+        );
+    }
+
+    #[test]
+    fn test_speed_does_not_depend_on_frame_rate() {
+        let area = Rect::new(0, 0, 40, 12);
+        let mut slow = logo("LOGO");
+        let mut fast = logo("LOGO");
+
+        // One 100ms frame covers the same ground as four 25ms frames
+        slow.tick(Duration::from_millis(100));
+        for _ in 0..4 {
+            fast.tick(Duration::from_millis(25));
+        }
+        let screen = |feature: &mut BouncingLogoFeature| -> String {
+            let lines = feature.render(area.clone());
+            lines.iter().map(|line| line.content() + "\n").collect()
+        };
+        assert_eq!(logo_row(&screen(&mut slow)), logo_row(&screen(&mut fast)));
     }
 }
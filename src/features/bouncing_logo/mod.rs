@@ -1,5 +1,4 @@
 pub mod bouncing_logo_logic;
 
-// Tests temporarily disabled to focus on main functionality
-// #[cfg(test)]
-// pub mod bouncing_logo_logic_tests;
+#[cfg(test)]
+pub mod bouncing_logo_logic_tests;
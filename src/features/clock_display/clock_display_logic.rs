@@ -1,72 +1,61 @@
-use crate::cli::cli_logic::Config;
-use crate::shared::shared_logic as shared;
-use crate::shared::{SimpleRenderer, TextLine, Rect};
+use crate::cli::config_logic::{ClockDisplayConfig, ClockFormat};
+use crate::features::{ScreensaverAction, ScreensaverFeature};
+use crate::shared::text_width::center_text;
+use crate::shared::{Rect, TextLine};
+use crate::styles::theme_logic::Theme;
 use chrono::{DateTime, Local};
-use crossterm::event::{read, Event, KeyCode, KeyEvent};
-use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
-use crossterm::style::Color;
-use std::io;
-use std::time::{Duration, SystemTime};
+use crossterm::event::{KeyCode, KeyEvent};
 
-pub struct ClockDisplay {
+pub struct ClockDisplayFeature {
     pub show_seconds: bool,
     pub show_date: bool,
-    pub time_format_24h: bool,
-    pub color: Color,
-    pub last_update: SystemTime,
+    pub format: ClockFormat,
+    theme: Theme,
 }
 
-impl Default for ClockDisplay {
+impl Default for ClockDisplayFeature {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl ClockDisplay {
+impl ClockDisplayFeature {
     pub fn new() -> Self {
+        Self::with_config(&ClockDisplayConfig::default())
+    }
+
+    pub fn with_config(config: &ClockDisplayConfig) -> Self {
         Self {
-            show_seconds: true,
+            show_seconds: config.show_seconds,
             show_date: true,
-            time_format_24h: true,
-            color: Color::Green,
-            last_update: SystemTime::now(),
+            format: config.format,
+            theme: Theme::default(),
         }
     }
 
-    pub fn with_config(show_seconds: bool, show_date: bool, time_format_24h: bool) -> Self {
-        Self {
-            show_seconds,
-            show_date,
-            time_format_24h,
-            color: Color::Green,
-            last_update: SystemTime::now(),
-        }
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
     }
 
-    fn get_current_time_string(&self) -> String {
+    pub fn get_current_time_string(&self) -> String {
         let now: DateTime<Local> = Local::now();
 
-        let time_str = if self.time_format_24h {
-            if self.show_seconds {
-                now.format("%H:%M:%S").to_string()
-            } else {
-                now.format("%H:%M").to_string()
-            }
-        } else if self.show_seconds {
-            now.format("%I:%M:%S %p").to_string()
-        } else {
-            now.format("%I:%M %p").to_string()
-        };
-
-        time_str
+        match (self.format, self.show_seconds) {
+            (ClockFormat::TwentyFourHour, true) => now.format("%H:%M:%S").to_string(),
+            (ClockFormat::TwentyFourHour, false) => now.format("%H:%M").to_string(),
+            (ClockFormat::TwelveHour, true) => now.format("%I:%M:%S %p").to_string(),
+            (ClockFormat::TwelveHour, false) => now.format("%I:%M %p").to_string(),
+            (ClockFormat::Unix, _) => now.timestamp().to_string(),
+        }
     }
 
-    fn get_current_date_string(&self) -> String {
+    pub fn get_current_date_string(&self) -> String {
         let now: DateTime<Local> = Local::now();
         now.format("%A, %B %d, %Y").to_string()
     }
 
-    fn create_digital_time(&self, time_str: &str) -> Vec<String> {
+    pub fn create_digital_time(&self, time_str: &str) -> Vec<String> {
         let digits = [
             [" ███ ", "█   █", "█   █", "█   █", " ███ "], // 0
             ["  █  ", " ██  ", "  █  ", "  █  ", " ███ "], // 1
@@ -99,100 +88,72 @@ impl ClockDisplay {
 
         result
     }
+}
 
-    pub fn render<'a>(&self, area: Rect, title: &'a str) -> Paragraph<'a> {
+impl ScreensaverFeature for ClockDisplayFeature {
+    fn render(&mut self, area: Rect) -> Vec<TextLine> {
         let time_str = self.get_current_time_string();
-        let date_str = if self.show_date {
-            self.get_current_date_string()
-        } else {
-            String::new()
+        // AM/PM is shown as text below the digits rather than as block glyphs
+        let (digits, suffix) = match time_str.split_once(' ') {
+            Some((digits, suffix)) => (digits.to_string(), Some(suffix.to_string())),
+            None => (time_str, None),
         };
-
-        let digital_time = self.create_digital_time(&time_str);
-
-        let mut lines = Vec::new();
-
-        // Add some vertical centering space
-        let vertical_padding = (area.height as usize).saturating_sub(10) / 2;
-        for _ in 0..vertical_padding {
-            lines.push(Line::from(""));
+        let width = area.width as usize;
+
+        let mut content = Vec::new();
+        for line in self.create_digital_time(&digits) {
+            content.push(TextLine::styled(
+                center_text(&line, width),
+                self.theme.primary.clone(),
+            ));
         }
-
-        // Add title if provided
-        if !title.is_empty() {
-            lines.push(Line::from(Span::styled(
-                title,
-                Style::default().fg(Color::Cyan),
-            )));
-            lines.push(Line::from(""));
-        }
-
-        // Add digital time display
-        for line in digital_time {
-            lines.push(Line::from(Span::styled(
-                line,
-                Style::default().fg(Color::White),
-            )));
+        if let Some(suffix) = suffix {
+            content.push(TextLine::styled(
+                center_text(&suffix, width),
+                self.theme.primary.clone(),
+            ));
         }
 
         // Add date if enabled
-        if self.show_date && !date_str.is_empty() {
-            lines.push(Line::from(""));
-            lines.push(Line::from(Span::styled(
-                date_str,
-                Style::default().fg(Color::Yellow),
-            )));
+        if self.show_date {
+            content.push(TextLine::new(String::new()));
+            content.push(TextLine::styled(
+                center_text(&self.get_current_date_string(), width),
+                self.theme.accent.clone(),
+            ));
         }
 
         // Add timezone info
-        let now: DateTime<Local> = Local::now();
-        let timezone_str = now.format("%Z").to_string();
+        let timezone_str = Local::now().format("%Z").to_string();
         if !timezone_str.is_empty() {
-            lines.push(Line::from(""));
-            lines.push(Line::from(Span::styled(
-                timezone_str,
-                Style::default().fg(Color::Gray),
-            )));
+            content.push(TextLine::new(String::new()));
+            content.push(TextLine::styled(
+                center_text(&timezone_str, width),
+                self.theme.dim.clone(),
+            ));
         }
 
-        Paragraph::new(lines).alignment(Alignment::Center).block(
-            Block::default()
-                .borders(Borders::ALL)
-                .style(Style::default().fg(Color::Blue)),
-        )
+        // Center the block vertically
+        let vertical_padding = (area.height as usize).saturating_sub(content.len()) / 2;
+        let mut lines = vec![TextLine::new(String::new()); vertical_padding];
+        lines.extend(content);
+        lines.truncate(area.height as usize);
+        lines
     }
-}
 
-pub fn run_screensaver(config: Config) -> io::Result<()> {
-    enable_raw_mode()?;
-    let stdout = io::stdout();
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
-    shared::clear_screen();
-
-    let clock = ClockDisplay::new();
-
-    loop {
-        terminal.draw(|f| {
-            let area = f.area();
-            let clock_widget = clock.render(area, &config.text);
-            f.render_widget(clock_widget, area);
-        })?;
-
-        // Non-blocking input check with shorter timeout for clock updates
-        if crossterm::event::poll(Duration::from_millis(500))? {
-            if let Event::Key(KeyEvent { code, .. }) = read()? {
-                match code {
-                    KeyCode::Esc => break,
-                    KeyCode::Enter => {
-                        log::info!("Enter pressed in clock_display screensaver, triggering additional options");
-                    }
-                    _ => {}
-                }
-            }
+    fn handle_input(&mut self, key_event: KeyEvent) -> ScreensaverAction {
+        match key_event.code {
+            KeyCode::Esc => ScreensaverAction::Exit,
+            KeyCode::Enter => ScreensaverAction::NextScreen,
+            _ => ScreensaverAction::Continue,
         }
     }
 
-    disable_raw_mode()?;
-    Ok(())
+    fn resize(&mut self, _new_area: Rect) {
+        // The digits are centered in whatever area render is given
+    }
+
+    fn name(&self) -> &'static str {
+        "clock_display"
+    }
 }
@@ -1,4 +1,4 @@
 pub mod clock_display_logic;
 
-// Tests temporarily disabled to focus on main functionality
-// pub mod clock_display_logic_tests;
+#[cfg(test)]
+pub mod clock_display_logic_tests;
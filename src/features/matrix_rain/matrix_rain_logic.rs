@@ -1,18 +1,16 @@
-use crate::cli::cli_logic::Config;
-use crate::shared::shared_logic as shared;
-use crossterm::event::{read, Event, KeyCode, KeyEvent};
-use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
-use rand::Rng;
-use ratatui::{
-    backend::CrosstermBackend,
-    layout::{Alignment, Rect},
-    style::{Color, Style},
-    text::{Line, Span},
-    widgets::{Block, Paragraph},
-    Terminal,
-};
-use std::io;
-use std::time::{Duration, Instant};
+use crate::cli::config_logic::{MatrixCharset, MatrixRainConfig};
+use crate::features::{ScreensaverAction, ScreensaverFeature};
+use crate::shared::text_width::display_width;
+use crate::shared::{Rect, SeededRng, TextLine};
+use crate::styles::style_logic::Style;
+use crate::styles::theme_logic::{shade, Theme};
+use crossterm::event::{KeyCode, KeyEvent};
+use std::time::Duration;
+
+const KATAKANA: &str =
+    "アイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワヲン";
+const ASCII: &str = "!\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
+const NUMBERS: &str = "0123456789";
 
 pub struct MatrixColumn {
     pub x: u16,
@@ -23,167 +21,189 @@ pub struct MatrixColumn {
 }
 
 impl MatrixColumn {
-    fn new(x: u16, _height: u16) -> Self {
-        let mut rng = rand::rng();
+    fn new(x: u16, _height: u16, charset: &[char], rng: &mut SeededRng) -> Self {
         Self {
             x,
             y: 0,
-            speed: rng.random_range(1..=3),
-            length: rng.random_range(5..=15),
-            characters: (0..15)
-                .map(|_| {
-                    let chars = "アイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワヲン";
-                    let chars: Vec<char> = chars.chars().collect();
-                    chars[rng.random_range(0..chars.len())]
-                })
-                .collect(),
+            speed: rng.range(1, 3) as u8,
+            length: rng.range(5, 15) as u8,
+            characters: (0..15).map(|_| *rng.choose(charset)).collect(),
         }
     }
 
-    fn update(&mut self, height: u16) {
+    fn update(&mut self, height: u16, charset: &[char], rng: &mut SeededRng) {
         self.y += self.speed as u16;
         if self.y > height + self.length as u16 {
             self.y = 0;
-            let mut rng = rand::rng();
-            self.speed = rng.random_range(1..=3);
-            self.length = rng.random_range(5..=15);
+            self.speed = rng.range(1, 3) as u8;
+            self.length = rng.range(5, 15) as u8;
             // Refresh some characters
             for i in 0..3 {
                 if i < self.characters.len() {
-                    let chars = "アイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワヲン";
-                    let chars: Vec<char> = chars.chars().collect();
-                    self.characters[i] = chars[rng.random_range(0..chars.len())];
+                    self.characters[i] = *rng.choose(charset);
                 }
             }
         }
     }
 }
 
-pub struct MatrixRain {
+pub struct MatrixRainFeature {
     pub columns: Vec<MatrixColumn>,
-    pub last_update: Instant,
+    // Time ticked but not yet spent on column steps
+    pub pending: Duration,
     pub update_interval: Duration,
+    charset: Vec<char>,
+    rng: SeededRng,
+    theme: Theme,
 }
 
-impl MatrixRain {
+impl MatrixRainFeature {
     pub fn new(width: u16, height: u16) -> Self {
-        let columns = (0..width)
+        Self::with_config(&MatrixRainConfig::default(), width, height)
+    }
+
+    pub fn with_config(config: &MatrixRainConfig, width: u16, height: u16) -> Self {
+        let charset = match config.charset {
+            MatrixCharset::Katakana => KATAKANA,
+            MatrixCharset::Ascii => ASCII,
+            MatrixCharset::Numbers => NUMBERS,
+        };
+        // Speed 1-10 maps onto 140ms-50ms between steps; the default of 5 is 100ms
+        let speed = config.speed.clamp(1, 10) as u64;
+
+        let mut matrix = Self {
+            columns: Vec::new(),
+            pending: Duration::ZERO,
+            update_interval: Duration::from_millis(150 - speed * 10),
+            charset: charset.chars().collect(),
+            rng: SeededRng::from_entropy(),
+            theme: Theme::default(),
+        };
+        matrix.reset_columns(width, height);
+        matrix
+    }
+
+    // Draw from `rng`; columns are rebuilt from it on the next render
+    pub fn with_rng(mut self, rng: SeededRng) -> Self {
+        self.rng = rng;
+        self.columns.clear();
+        self
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    fn reset_columns(&mut self, width: u16, height: u16) {
+        self.columns = (0..width)
             .step_by(2)
-            .map(|x| MatrixColumn::new(x, height))
+            .map(|x| MatrixColumn::new(x, height, &self.charset, &mut self.rng))
             .collect();
-
-        Self {
-            columns,
-            last_update: Instant::now(),
-            update_interval: Duration::from_millis(100),
-        }
     }
 
+    // Step every column once for each whole update interval ticked since the
+    // last update, so the rain falls at the same pace at any frame rate
     pub fn update(&mut self, width: u16, height: u16) {
-        if self.last_update.elapsed() >= self.update_interval {
-            // Adjust column count if terminal size changed
-            let expected_columns = (width as usize).div_ceil(2);
-            if self.columns.len() != expected_columns {
-                self.columns = (0..width)
-                    .step_by(2)
-                    .map(|x| MatrixColumn::new(x, height))
-                    .collect();
-            }
+        // Adjust column count if terminal size changed
+        let expected_columns = (width as usize).div_ceil(2);
+        if self.columns.len() != expected_columns {
+            self.reset_columns(width, height);
+        }
 
+        let interval = self.update_interval.max(Duration::from_millis(1));
+        while self.pending >= interval {
             for column in &mut self.columns {
-                column.update(height);
+                column.update(height, &self.charset, &mut self.rng);
             }
-            self.last_update = Instant::now();
+            self.pending -= interval;
         }
     }
 
-    pub fn render(&self, area: Rect) -> Vec<Line> {
-        let mut lines = vec![
-            Line::from(vec![Span::raw(" ".repeat(area.width as usize))]);
-            area.height as usize
-        ];
+    fn render_rows(&self, area: &Rect) -> Vec<TextLine> {
+        let width = area.width as usize;
+        let mut grid: Vec<Vec<Option<(char, Style)>>> =
+            vec![vec![None; width]; area.height as usize];
 
         for column in &self.columns {
             if column.x >= area.width {
                 continue;
             }
 
-            for (i, &ch) in column.characters.iter().enumerate() {
+            let visible = (column.length as usize).min(column.characters.len());
+            for (i, &ch) in column.characters.iter().take(visible).enumerate() {
                 let char_y = column.y as i32 - i as i32;
                 if char_y >= 0 && char_y < area.height as i32 {
-                    let intensity = 1.0 - (i as f32 / column.length as f32);
-                    let color = if i == 0 {
-                        Color::White // Head of the trail
-                    } else if intensity > 0.7 {
-                        Color::Rgb(0, 255, 0) // Bright green
-                    } else if intensity > 0.3 {
-                        Color::Rgb(0, 180, 0) // Medium green
-                    } else {
-                        Color::Rgb(0, 100, 0) // Dark green
-                    };
-
-                    let line_index = char_y as usize;
-                    if line_index < lines.len() && column.x < area.width {
-                        let mut line_content = " ".repeat(area.width as usize);
-                        line_content
-                            .replace_range(column.x as usize..=column.x as usize, &ch.to_string());
-                        lines[line_index] = Line::from(vec![Span::styled(
-                            line_content,
-                            Style::default().fg(color),
-                        )]);
-                    }
+                    let style = trail_style(&self.theme, i, column.length);
+                    grid[char_y as usize][column.x as usize] = Some((ch, style));
                 }
             }
         }
 
-        lines
+        let blank = Style::new();
+        grid.into_iter()
+            .map(|row| {
+                let mut line = TextLine::default();
+                let mut x = 0;
+                while x < row.len() {
+                    match &row[x] {
+                        Some((ch, style)) => {
+                            let glyph = ch.to_string();
+                            line.push_styled(&glyph, style);
+                            // Full-width katakana covers the next cell too
+                            x += display_width(&glyph).max(1);
+                        }
+                        None => {
+                            line.push_styled(" ", &blank);
+                            x += 1;
+                        }
+                    }
+                }
+                line
+            })
+            .collect()
     }
 }
 
-pub fn run_screensaver(config: Config) -> io::Result<()> {
-    enable_raw_mode()?;
-    let stdout = io::stdout();
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
-    shared::clear_screen();
-
-    let size = terminal.size()?;
-    let mut matrix = MatrixRain::new(size.width, size.height);
-
-    loop {
-        let size = terminal.size()?;
-        matrix.update(size.width, size.height);
-
-        terminal.draw(|f| {
-            let area = f.area();
-            let lines = matrix.render(area);
-
-            let block = Block::default()
-                .title(format!(" {} ", config.text))
-                .title_alignment(Alignment::Center)
-                .style(Style::default().fg(Color::Green));
-
-            let paragraph = Paragraph::new(lines)
-                .block(block)
-                .style(Style::default().bg(Color::Black));
-
-            f.render_widget(paragraph, area);
-        })?;
-
-        // Non-blocking input check
-        if crossterm::event::poll(Duration::from_millis(50))? {
-            if let Event::Key(KeyEvent { code, .. }) = read()? {
-                match code {
-                    KeyCode::Esc => break,
-                    KeyCode::Enter => {
-                        log::info!("Enter pressed in matrix_rain screensaver, triggering additional options");
-                    }
-                    _ => {}
-                }
-            }
+// Fade a trail from a primary-colored head through shades of the first
+// palette color (green in the default theme)
+pub(crate) fn trail_style(theme: &Theme, index: usize, length: u8) -> Style {
+    let intensity = 1.0 - (index as f32 / length as f32);
+    let trail = theme.palette_color(0);
+    if index == 0 {
+        theme.primary.clone().bold() // Head of the trail
+    } else if intensity > 0.7 {
+        Style::new().fg(trail)
+    } else if intensity > 0.3 {
+        Style::new().fg(shade(trail, 180.0 / 255.0))
+    } else {
+        Style::new().fg(shade(trail, 100.0 / 255.0)).dim()
+    }
+}
+
+impl ScreensaverFeature for MatrixRainFeature {
+    fn tick(&mut self, dt: Duration) {
+        self.pending += dt;
+    }
+
+    fn render(&mut self, area: Rect) -> Vec<TextLine> {
+        self.update(area.width, area.height);
+        self.render_rows(&area)
+    }
+
+    fn handle_input(&mut self, key_event: KeyEvent) -> ScreensaverAction {
+        match key_event.code {
+            KeyCode::Esc => ScreensaverAction::Exit,
+            KeyCode::Enter => ScreensaverAction::NextScreen,
+            _ => ScreensaverAction::Continue,
         }
     }
 
-    disable_raw_mode()?;
-    Ok(())
+    fn resize(&mut self, new_area: Rect) {
+        self.reset_columns(new_area.width, new_area.height);
+    }
+
+    fn name(&self) -> &'static str {
+        "matrix_rain"
+    }
 }
@@ -1,18 +1,24 @@
 #[cfg(test)]
 mod matrix_rain_tests {
-    use super::matrix_rain_logic::MatrixRain;
-    use ratatui::layout::Rect;
+    use crate::cli::config_logic::{MatrixCharset, MatrixRainConfig};
+    use crate::features::matrix_rain::matrix_rain_logic::{trail_style, MatrixRainFeature};
+    use crate::features::{ScreensaverAction, ScreensaverFeature};
+    use crate::shared::Rect;
+    use crate::styles::theme_logic::Theme;
+    use crossterm::event::{KeyCode, KeyEvent};
+    use crossterm::style::{Attribute, Color};
+    use std::time::Duration;
 
     #[test]
     fn test_matrix_rain_creation() {
-        let matrix = MatrixRain::new(80, 24);
+        let matrix = MatrixRainFeature::new(80, 24);
         assert_eq!(matrix.columns.len(), 40); // Every 2nd column
-        assert!(matrix.update_interval.as_millis() > 0);
+        assert_eq!(matrix.update_interval, Duration::from_millis(100));
     }
 
     #[test]
     fn test_matrix_rain_adapts_to_size_changes() {
-        let mut matrix = MatrixRain::new(80, 24);
+        let mut matrix = MatrixRainFeature::new(80, 24);
         let initial_columns = matrix.columns.len();
 
         // Simulate size change
@@ -25,70 +31,149 @@ mod matrix_rain_tests {
 
     #[test]
     fn test_matrix_rain_render_produces_output() {
-        let matrix = MatrixRain::new(20, 10);
-        let area = Rect::new(0, 0, 20, 10);
-        let lines = matrix.render(area);
+        let mut matrix = MatrixRainFeature::new(20, 10);
+        let lines = matrix.render(Rect::new(0, 0, 20, 10));
 
         assert_eq!(lines.len(), 10); // Should produce lines for each row
     }
 
     #[test]
-    fn test_matrix_column_creation() {
-        let column = MatrixColumn::new(5, 24);
-        assert_eq!(column.x, 5);
-        assert_eq!(column.y, 0);
-        assert!(column.speed >= 1 && column.speed <= 3);
-        assert!(column.length >= 5 && column.length <= 15);
-        assert!(!column.characters.is_empty());
+    fn test_matrix_columns_start_in_range() {
+        let matrix = MatrixRainFeature::new(24, 24);
+
+        for (i, column) in matrix.columns.iter().enumerate() {
+            assert_eq!(column.x, i as u16 * 2);
+            assert_eq!(column.y, 0);
+            assert!(column.speed >= 1 && column.speed <= 3);
+            assert!(column.length >= 5 && column.length <= 15);
+            assert!(!column.characters.is_empty());
+        }
     }
 
     #[test]
-    fn test_matrix_column_update() {
-        let mut column = MatrixColumn::new(0, 10);
-        let initial_y = column.y;
-        column.update(10);
+    fn test_matrix_columns_wrap_around() {
+        let mut matrix = MatrixRainFeature::new(10, 10);
+        for column in &mut matrix.columns {
+            column.y = 30; // Beyond height + maximum length
+        }
+
+        matrix.tick(matrix.update_interval);
+        matrix.update(10, 10);
+
+        assert!(matrix.columns.iter().all(|column| column.y == 0));
+    }
 
-        // Y position should increase
-        assert!(column.y >= initial_y);
+    #[test]
+    fn test_matrix_rain_handles_small_and_large_terminals() {
+        let mut matrix = MatrixRainFeature::new(10, 5);
+        assert_eq!(matrix.render(Rect::new(0, 0, 10, 5)).len(), 5);
+        assert_eq!(matrix.columns.len(), 5);
+
+        let mut matrix = MatrixRainFeature::new(200, 80);
+        assert_eq!(matrix.render(Rect::new(0, 0, 200, 80)).len(), 80);
+        assert_eq!(matrix.columns.len(), 100);
     }
 
     #[test]
-    fn test_matrix_column_wraps_around() {
-        let mut column = MatrixColumn::new(0, 10);
-        column.y = 25; // Set y beyond height + length
-        column.update(10);
+    fn test_matrix_characters_follow_charset() {
+        let katakana = MatrixRainFeature::new(24, 24);
+        let valid_chars = "アイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワヲン";
+        for column in &katakana.columns {
+            for &ch in &column.characters {
+                assert!(valid_chars.contains(ch), "Invalid character found: {}", ch);
+            }
+        }
+
+        let config = MatrixRainConfig {
+            charset: MatrixCharset::Numbers,
+            ..MatrixRainConfig::default()
+        };
+        let numbers = MatrixRainFeature::with_config(&config, 24, 24);
+        for column in &numbers.columns {
+            assert!(column.characters.iter().all(char::is_ascii_digit));
+        }
+    }
 
-        // Should wrap around to 0
-        assert_eq!(column.y, 0);
+    #[test]
+    fn test_config_speed_controls_update_interval() {
+        let config = MatrixRainConfig {
+            speed: 10,
+            ..MatrixRainConfig::default()
+        };
+        let fast = MatrixRainFeature::with_config(&config, 20, 10);
+
+        assert_eq!(fast.update_interval, Duration::from_millis(50));
     }
 
     #[test]
-    fn test_matrix_rain_handles_small_terminal() {
-        let matrix = MatrixRain::new(10, 5);
-        let area = Rect::new(0, 0, 10, 5);
-        let lines = matrix.render(area);
+    fn test_rain_falls_at_the_same_pace_at_any_fps() {
+        let area = Rect::new(0, 0, 40, 100);
+        let mut slow = MatrixRainFeature::new(40, 100);
+        let mut fast = MatrixRainFeature::new(40, 100);
+        for column in slow.columns.iter_mut().chain(fast.columns.iter_mut()) {
+            column.speed = 1;
+        }
+        // Half a step of head start, as sixty 1/60s frames fall a few
+        // nanoseconds short of a second
+        slow.tick(Duration::from_millis(50));
+        fast.tick(Duration::from_millis(50));
+
+        // One second at 5 fps and at 60 fps
+        for _ in 0..5 {
+            slow.tick(Duration::from_millis(200));
+            slow.render(area.clone());
+        }
+        for _ in 0..60 {
+            fast.tick(Duration::from_secs(1) / 60);
+            fast.render(area.clone());
+        }
 
-        assert_eq!(lines.len(), 5);
-        assert_eq!(matrix.columns.len(), 5); // 10 / 2 = 5 columns
+        // Ten 100ms steps either way
+        assert!(slow.columns.iter().all(|column| column.y == 10));
+        assert!(fast.columns.iter().all(|column| column.y == 10));
     }
 
     #[test]
-    fn test_matrix_rain_handles_large_terminal() {
-        let matrix = MatrixRain::new(200, 80);
-        let area = Rect::new(0, 0, 200, 80);
-        let lines = matrix.render(area);
+    fn test_render_without_tick_keeps_columns_still() {
+        let mut matrix = MatrixRainFeature::new(20, 10);
+        for _ in 0..3 {
+            matrix.render(Rect::new(0, 0, 20, 10));
+        }
 
-        assert_eq!(lines.len(), 80);
-        assert_eq!(matrix.columns.len(), 100); // 200 / 2 = 100 columns
+        assert!(matrix.columns.iter().all(|column| column.y == 0));
     }
 
     #[test]
-    fn test_matrix_characters_are_valid() {
-        let column = MatrixColumn::new(0, 24);
-        let valid_chars = "アイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワヲン";
+    fn test_handle_keys() {
+        let mut matrix = MatrixRainFeature::new(20, 10);
+
+        assert_eq!(
+            matrix.handle_input(KeyEvent::from(KeyCode::Esc)),
+            ScreensaverAction::Exit
+        );
+        assert_eq!(
+            matrix.handle_input(KeyEvent::from(KeyCode::Enter)),
+            ScreensaverAction::NextScreen
+        );
+    }
 
-        for &ch in &column.characters {
-            assert!(valid_chars.contains(ch), "Invalid character found: {}", ch);
-        }
+    #[test]
+    fn test_trail_fades_from_head() {
+        let theme = Theme::default();
+        let head = trail_style(&theme, 0, 10);
+        assert_eq!(head.color, Some(Color::White));
+        assert!(head.attributes.contains(&Attribute::Bold));
+
+        assert_eq!(
+            trail_style(&theme, 1, 10).color,
+            Some(Color::Rgb { r: 0, g: 255, b: 0 })
+        );
+        assert_eq!(
+            trail_style(&theme, 5, 10).color,
+            Some(Color::Rgb { r: 0, g: 180, b: 0 })
+        );
+        assert!(trail_style(&theme, 9, 10)
+            .attributes
+            .contains(&Attribute::Dim));
     }
 }
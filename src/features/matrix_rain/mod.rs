@@ -1,4 +1,4 @@
 pub mod matrix_rain_logic;
 
-// Tests temporarily disabled to focus on main functionality
-// pub mod matrix_rain_logic_tests;
+#[cfg(test)]
+pub mod matrix_rain_logic_tests;
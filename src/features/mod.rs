@@ -15,23 +15,26 @@
 // - starfield: 3D starfield simulation
 
 pub mod bouncing_logo;
-// Temporarily disabled complex features that need ratatui conversion
-// pub mod clock_display;
-// pub mod matrix_rain;
-// pub mod starfield;
-// pub mod system_info;
+pub mod clock_display;
+pub mod matrix_rain;
+pub mod starfield;
+pub mod system_info;
 pub mod text_display;
-// pub mod wave_animation;
+pub mod wave_animation;
+
+use crate::cli::cli_logic::Config;
+use crate::shared::SeededRng;
+use crate::styles::theme_logic::theme_for;
+use std::time::Duration;
 
 // Re-export common types for easier usage
-// pub use text_display::text_display_logic::TextDisplay; // text_display uses functions, not structs
 pub use bouncing_logo::bouncing_logo_logic::BouncingLogoFeature;
-// Temporarily disabled exports for complex features
-// pub use clock_display::clock_display_logic::ClockDisplay;
-// pub use matrix_rain::matrix_rain_logic::MatrixRain;
-// pub use starfield::starfield_logic::StarfieldFeature;
-// pub use system_info::system_info_logic::SystemInfoFeature;
-// pub use wave_animation::wave_animation_logic::WaveAnimationFeature;
+pub use clock_display::clock_display_logic::ClockDisplayFeature;
+pub use matrix_rain::matrix_rain_logic::MatrixRainFeature;
+pub use starfield::starfield_logic::StarfieldFeature;
+pub use system_info::system_info_logic::SystemInfoFeature;
+pub use text_display::text_display_logic::TextDisplayFeature;
+pub use wave_animation::wave_animation_logic::WaveAnimationFeature;
 
 // Common screensaver action enum (each feature should implement this)
 #[derive(Debug, PartialEq, Clone)]
@@ -39,10 +42,24 @@ pub enum ScreensaverAction {
     Continue,
     Exit,
     NextScreen,
+    // Keep running, but the feature drew outside the renderer (e.g. an action
+    // run in the terminal), so the whole screen has to be repainted
+    Repaint,
+}
+
+// Frame length the per-frame speeds of features were originally tuned for (20 FPS)
+pub const REFERENCE_FRAME: Duration = Duration::from_millis(50);
+
+// How many reference frames of motion `dt` is worth, so features can keep
+// their per-frame speeds while moving at the same real-time pace at any FPS
+pub fn frame_steps(dt: Duration) -> f64 {
+    dt.as_secs_f64() / REFERENCE_FRAME.as_secs_f64()
 }
 
 // Feature trait that all screensaver features should implement
 pub trait ScreensaverFeature {
+    // Advance animations by the real time since the previous frame; called before render
+    fn tick(&mut self, _dt: Duration) {}
     fn render(&mut self, area: crate::shared::Rect) -> Vec<crate::shared::TextLine>;
     fn handle_input(&mut self, key_event: crossterm::event::KeyEvent) -> ScreensaverAction;
     fn resize(&mut self, new_area: crate::shared::Rect);
@@ -53,25 +70,64 @@ pub trait ScreensaverFeature {
 pub fn get_available_features() -> Vec<&'static str> {
     vec![
         "text_display",
+        "matrix_rain",
+        "clock_display",
         "bouncing_logo",
-        // Temporarily disabled complex features
-        // "matrix_rain",
-        // "clock_display",
-        // "system_info",
-        // "wave_animation",
-        // "starfield",
+        "system_info",
+        "wave_animation",
+        "starfield",
     ]
-} // Feature descriptions for user interfaces and documentation
+}
+
+// Feature descriptions for user interfaces and documentation
 pub fn get_feature_description(feature_name: &str) -> Option<&'static str> {
     match feature_name {
         "text_display" => Some("Static and animated text display with customizable content"),
+        "matrix_rain" => Some("Matrix-style falling character rain animation"),
+        "clock_display" => Some("Digital clock display with multiple format options"),
         "bouncing_logo" => Some("Animated bouncing text or logo with physics"),
-        // Temporarily disabled features
-        // "matrix_rain" => Some("Matrix-style falling character rain animation"),
-        // "clock_display" => Some("Digital clock display with multiple format options"),
-        // "system_info" => Some("Live system information and runtime statistics"),
-        // "wave_animation" => Some("Smooth ASCII wave animations with physics simulation"),
-        // "starfield" => Some("3D starfield simulation with depth and movement"),
+        "system_info" => Some("Live system information and runtime statistics"),
+        "wave_animation" => Some("Smooth ASCII wave animations with physics simulation"),
+        "starfield" => Some("3D starfield simulation with depth and movement"),
+        _ => None,
+    }
+}
+
+// Instantiate a registered feature by name, configured from its config section
+// and drawn with the selected theme. Randomized features get their own stream
+// forked from `rng`.
+pub fn create_feature(
+    feature_name: &str,
+    config: &Config,
+    rng: &mut SeededRng,
+) -> Option<Box<dyn ScreensaverFeature>> {
+    let theme = theme_for(config);
+    match feature_name {
+        "text_display" => Some(Box::new(TextDisplayFeature::new(config).with_theme(theme))),
+        "matrix_rain" => Some(Box::new(
+            MatrixRainFeature::with_config(&config.features.matrix_rain, 80, 24)
+                .with_rng(rng.fork())
+                .with_theme(theme),
+        )),
+        "clock_display" => Some(Box::new(
+            ClockDisplayFeature::with_config(&config.features.clock_display).with_theme(theme),
+        )),
+        "bouncing_logo" => Some(Box::new(
+            BouncingLogoFeature::with_config(&config.features.bouncing_logo)
+                .with_rng(rng.fork())
+                .with_theme(theme),
+        )),
+        "system_info" => Some(Box::new(
+            SystemInfoFeature::with_config(&config.features.system_info).with_theme(theme),
+        )),
+        "wave_animation" => Some(Box::new(
+            WaveAnimationFeature::with_config(&config.features.wave_animation).with_theme(theme),
+        )),
+        "starfield" => Some(Box::new(
+            StarfieldFeature::with_config(&config.features.starfield)
+                .with_rng(rng.fork())
+                .with_theme(theme),
+        )),
         _ => None,
     }
 }
@@ -1,5 +1,4 @@
 pub mod starfield_logic;
 
-// Tests temporarily disabled to focus on main functionality
-// #[cfg(test)]
-// pub mod starfield_logic_tests;
+#[cfg(test)]
+pub mod starfield_logic_tests;
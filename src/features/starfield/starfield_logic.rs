@@ -1,28 +1,31 @@
+use crate::cli::config_logic::StarfieldConfig;
+use crate::features::{frame_steps, ScreensaverAction, ScreensaverFeature};
+use crate::shared::text_width::center_text;
+use crate::shared::{Rect, SeededRng, TextLine};
+use crate::styles::style_logic::Style;
+use crate::styles::theme_logic::Theme;
 use crossterm::event::{KeyCode, KeyEvent};
-use ratatui::{
-    layout::Rect,
-    style::{Color, Style},
-    text::{Line, Span},
-};
+use crossterm::style::Color;
 use std::collections::HashMap;
+use std::time::Duration;
 
 #[derive(Clone)]
-struct Star {
-    x: f64,
-    y: f64,
-    z: f64,
-    char: char,
-    color: Color,
+pub struct Star {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub char: char,
+    pub color: Color,
 }
 
 pub struct StarfieldFeature {
-    stars: Vec<Star>,
-    star_count: usize,
-    speed: f32,
-    #[allow(dead_code)]
-    last_update: u64,
-    area_width: u16,
-    area_height: u16,
+    pub stars: Vec<Star>,
+    pub star_count: usize,
+    pub speed: f32,
+    pub area_width: u16,
+    pub area_height: u16,
+    rng: SeededRng,
+    theme: Theme,
 }
 
 impl Default for StarfieldFeature {
@@ -37,13 +40,85 @@ impl StarfieldFeature {
             stars: Vec::new(),
             star_count: 100,
             speed: 1.0,
-            last_update: 0,
             area_width: 80,
             area_height: 24,
+            rng: SeededRng::from_entropy(),
+            theme: Theme::default(),
         }
     }
 
-    pub fn render(&mut self, area: Rect) -> Vec<Line> {
+    pub fn with_config(config: &StarfieldConfig) -> Self {
+        Self {
+            star_count: config.star_count,
+            speed: config.speed as f32,
+            ..Self::new()
+        }
+    }
+
+    pub fn with_rng(mut self, rng: SeededRng) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    // Stars are colored from the theme's palette
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub fn initialize_stars(&mut self) {
+        self.stars.clear();
+        let star_chars = ['*', '·', '°', '+', '×'];
+        let color_count = self.theme.palette.len().max(1);
+
+        for _ in 0..self.star_count {
+            let z = (self.rng.next_f64() * 20.0) + 1.0; // Distance from viewer
+            let x = (self.rng.next_f64() - 0.5) * 100.0; // X position in 3D space
+            let y = (self.rng.next_f64() - 0.5) * 100.0; // Y position in 3D space
+
+            let char_idx = (self.rng.next_f64() * star_chars.len() as f64) as usize;
+            let color_idx = (self.rng.next_f64() * color_count as f64) as usize;
+
+            self.stars.push(Star {
+                x,
+                y,
+                z,
+                char: star_chars[char_idx.min(star_chars.len() - 1)],
+                color: self.theme.palette_color(color_idx),
+            });
+        }
+    }
+
+    // Move stars towards the viewer; speed is in depth units per reference frame
+    pub fn update_stars(&mut self, dt: Duration) {
+        let step = self.speed as f64 * frame_steps(dt);
+        for star in &mut self.stars {
+            star.z -= step;
+
+            // Reset star when it gets too close
+            if star.z <= 0.1 {
+                star.z = 20.0;
+                star.x = (self.rng.next_f64() - 0.5) * 100.0;
+                star.y = (self.rng.next_f64() - 0.5) * 100.0;
+            }
+        }
+    }
+}
+
+// Screen cell for a perspective-projected coordinate centered on the middle
+// of an axis `size` cells long, or None when it falls off either edge
+pub(crate) fn project(coordinate: f64, size: u16) -> Option<usize> {
+    let half = size as f64 / 2.0;
+    let cell = (coordinate * half + half).floor();
+    (cell >= 0.0 && cell < size as f64).then_some(cell as usize)
+}
+
+impl ScreensaverFeature for StarfieldFeature {
+    fn tick(&mut self, dt: Duration) {
+        self.update_stars(dt);
+    }
+
+    fn render(&mut self, area: Rect) -> Vec<TextLine> {
         // Initialize or resize stars if area changed
         if self.area_width != area.width || self.area_height != area.height || self.stars.is_empty()
         {
@@ -52,81 +127,50 @@ impl StarfieldFeature {
             self.initialize_stars();
         }
 
-        self.update_stars();
-
         let mut lines = Vec::new();
-        let mut star_map: HashMap<(usize, usize), Star> = HashMap::new();
+        let mut star_map: HashMap<(usize, usize), &Star> = HashMap::new();
 
         // Map stars to screen positions
         for star in &self.stars {
-            let screen_x = ((star.x / star.z) * (area.width as f64 / 2.0)
-                + (area.width as f64 / 2.0)) as usize;
-            let screen_y = ((star.y / star.z) * (area.height as f64 / 2.0)
-                + (area.height as f64 / 2.0)) as usize;
-
-            if screen_x < area.width as usize && screen_y < area.height as usize {
-                star_map.insert((screen_x, screen_y), star.clone());
+            if let (Some(screen_x), Some(screen_y)) = (
+                project(star.x / star.z, area.width),
+                project(star.y / star.z, area.height),
+            ) {
+                star_map.insert((screen_x, screen_y), star);
             }
         }
 
-        // Generate lines with stars
+        // Generate lines with stars, each in its own color
+        let blank = Style::new();
         for y in 0..area.height {
-            let mut line_spans = Vec::new();
-            let mut current_line = String::new();
-            let mut current_color = Color::White;
-
+            let mut line = TextLine::default();
             for x in 0..area.width {
-                if let Some(star) = star_map.get(&(x as usize, y as usize)) {
-                    // Finish current span if color changes
-                    if !current_line.is_empty() && current_color != star.color {
-                        line_spans.push(Span::styled(
-                            current_line.clone(),
-                            Style::default().fg(current_color),
-                        ));
-                        current_line.clear();
-                    }
-
-                    current_line.push(star.char);
-                    current_color = star.color;
-                } else {
-                    // Finish current span if we need to add a space
-                    if !current_line.is_empty() && current_color != Color::Black {
-                        line_spans.push(Span::styled(
-                            current_line.clone(),
-                            Style::default().fg(current_color),
-                        ));
-                        current_line.clear();
+                match star_map.get(&(x as usize, y as usize)) {
+                    Some(star) => {
+                        // Stars close to the viewer are drawn bold
+                        let style = Style::new().fg(star.color);
+                        let style = if star.z < 5.0 { style.bold() } else { style };
+                        line.push_styled(&star.char.to_string(), &style);
                     }
-
-                    current_line.push(' ');
-                    current_color = Color::Black;
+                    None => line.push_styled(" ", &blank),
                 }
             }
-
-            // Add final span for the line
-            if !current_line.is_empty() {
-                line_spans.push(Span::styled(
-                    current_line,
-                    Style::default().fg(current_color),
-                ));
-            }
-
-            lines.push(Line::from(line_spans));
+            lines.push(line);
         }
 
         // Add controls at bottom if there's space
         if area.height > 2 {
             let last_line_idx = lines.len() - 1;
-            lines[last_line_idx] = Line::from(vec![Span::styled(
+            lines[last_line_idx] = TextLine::styled(
                 center_text("Press ESC to exit | +/- for speed", area.width as usize),
-                Style::default().fg(Color::Gray),
-            )]);
+                self.theme.dim.clone(),
+            );
         }
 
         lines
     }
 
-    pub fn handle_input(&mut self, key_event: KeyEvent) -> ScreensaverAction {
+    fn handle_input(&mut self, key_event: KeyEvent) -> ScreensaverAction {
         match key_event.code {
             KeyCode::Esc => ScreensaverAction::Exit,
             KeyCode::Enter => ScreensaverAction::NextScreen,
@@ -153,78 +197,13 @@ impl StarfieldFeature {
         }
     }
 
-    pub fn resize(&mut self, new_area: Rect) {
+    fn resize(&mut self, new_area: Rect) {
         self.area_width = new_area.width;
         self.area_height = new_area.height;
         self.initialize_stars();
     }
 
-    fn initialize_stars(&mut self) {
-        self.stars.clear();
-        let star_chars = ['*', '·', '°', '+', '×'];
-        let star_colors = [
-            Color::White,
-            Color::Yellow,
-            Color::Cyan,
-            Color::Blue,
-            Color::Magenta,
-        ];
-
-        for _ in 0..self.star_count {
-            let z = (rand_f64() * 20.0) + 1.0; // Distance from viewer
-            let x = (rand_f64() - 0.5) * 100.0; // X position in 3D space
-            let y = (rand_f64() - 0.5) * 100.0; // Y position in 3D space
-
-            let char_idx = (rand_f64() * star_chars.len() as f64) as usize;
-            let color_idx = (rand_f64() * star_colors.len() as f64) as usize;
-
-            self.stars.push(Star {
-                x,
-                y,
-                z,
-                char: star_chars[char_idx.min(star_chars.len() - 1)],
-                color: star_colors[color_idx.min(star_colors.len() - 1)],
-            });
-        }
-    }
-
-    fn update_stars(&mut self) {
-        for star in &mut self.stars {
-            star.z -= self.speed as f64;
-
-            // Reset star when it gets too close
-            if star.z <= 0.1 {
-                star.z = 20.0;
-                star.x = (rand_f64() - 0.5) * 100.0;
-                star.y = (rand_f64() - 0.5) * 100.0;
-            }
-        }
-    }
-}
-
-#[derive(Debug, PartialEq)]
-pub enum ScreensaverAction {
-    Continue,
-    Exit,
-    NextScreen,
-}
-
-fn center_text(text: &str, width: usize) -> String {
-    let text_len = text.len();
-    if text_len >= width {
-        text.to_string()
-    } else {
-        let padding = (width - text_len) / 2;
-        format!("{}{}", " ".repeat(padding), text)
-    }
-}
-
-// Simple pseudo-random number generator for consistent behavior
-static mut RAND_SEED: u64 = 1;
-
-fn rand_f64() -> f64 {
-    unsafe {
-        RAND_SEED = RAND_SEED.wrapping_mul(1103515245).wrapping_add(12345);
-        (RAND_SEED as f64) / (u64::MAX as f64)
+    fn name(&self) -> &'static str {
+        "starfield"
     }
 }
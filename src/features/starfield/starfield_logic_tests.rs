@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod starfield_tests {
+    use crate::cli::config_logic::StarfieldConfig;
+    use crate::features::starfield::starfield_logic::*;
+    use crate::features::{ScreensaverAction, ScreensaverFeature};
+    use crate::shared::text_width::center_text;
+    use crate::shared::{Rect, SeededRng};
+    use crossterm::event::{KeyCode, KeyEvent};
+    use crossterm::style::Color;
+    use std::time::Duration;
+
+    #[test]
+    fn test_starfield_creation() {
+        let feature = StarfieldFeature::new();
+        assert_eq!(feature.star_count, 100);
+        assert_eq!(feature.speed, 1.0);
+        assert_eq!(feature.area_width, 80);
+        assert_eq!(feature.area_height, 24);
+    }
+
+    #[test]
+    fn test_starfield_with_config() {
+        let feature = StarfieldFeature::with_config(&StarfieldConfig {
+            enabled: true,
+            star_count: 50,
+            speed: 2.5,
+        });
+        assert_eq!(feature.star_count, 50);
+        assert_eq!(feature.speed, 2.5);
+    }
+
+    #[test]
+    fn test_handle_escape_key() {
+        let mut feature = StarfieldFeature::new();
+        let action = feature.handle_input(KeyEvent::from(KeyCode::Esc));
+        assert_eq!(action, ScreensaverAction::Exit);
+    }
+
+    #[test]
+    fn test_speed_adjustment() {
+        let mut feature = StarfieldFeature::new();
+        let initial_speed = feature.speed;
+
+        // Increase speed
+        feature.handle_input(KeyEvent::from(KeyCode::Char('+')));
+        assert!(feature.speed > initial_speed);
+
+        // Decrease speed
+        feature.handle_input(KeyEvent::from(KeyCode::Char('-')));
+        assert!(feature.speed < initial_speed + 0.2);
+
+        // Test limits
+        for _ in 0..20 {
+            feature.handle_input(KeyEvent::from(KeyCode::Char('+')));
+        }
+        assert!(feature.speed <= 5.0);
+
+        for _ in 0..20 {
+            feature.handle_input(KeyEvent::from(KeyCode::Char('-')));
+        }
+        assert!(feature.speed >= 0.1);
+    }
+
+    #[test]
+    fn test_star_count_change() {
+        let mut feature = StarfieldFeature::new();
+        let initial_count = feature.star_count;
+
+        feature.handle_input(KeyEvent::from(KeyCode::Char('s')));
+        assert_ne!(feature.star_count, initial_count);
+    }
+
+    #[test]
+    fn test_center_text() {
+        assert_eq!(center_text("test", 10), "   test");
+        assert_eq!(center_text("hello world", 8), "hello world");
+    }
+
+    #[test]
+    fn test_resize_updates_dimensions() {
+        let mut feature = StarfieldFeature::new();
+        let new_area = Rect::new(0, 0, 120, 40);
+
+        feature.resize(new_area);
+        assert_eq!(feature.area_width, 120);
+        assert_eq!(feature.area_height, 40);
+    }
+
+    #[test]
+    fn test_star_initialization() {
+        let mut feature = StarfieldFeature::new();
+        feature.initialize_stars();
+
+        assert_eq!(feature.stars.len(), feature.star_count);
+
+        for star in &feature.stars {
+            assert!(star.z > 0.0);
+            assert!(star.z <= 21.0); // Max initial Z + 1
+        }
+    }
+
+    #[test]
+    fn test_render_fills_area() {
+        let mut feature = StarfieldFeature::new();
+        let lines = feature.render(Rect::new(0, 0, 40, 12));
+
+        assert_eq!(lines.len(), 12);
+        assert!(lines[11].content().contains("ESC"));
+    }
+
+    #[test]
+    fn test_stars_keep_their_color() {
+        let mut feature = StarfieldFeature::new();
+        feature.initialize_stars();
+        for star in &mut feature.stars {
+            star.x = 0.0;
+            star.y = 0.0;
+            star.z = 10.0;
+            star.color = Color::Magenta;
+        }
+        feature.area_width = 40;
+        feature.area_height = 12;
+        let lines = feature.render(Rect::new(0, 0, 40, 12));
+
+        assert!(lines[6].spans.iter().any(
+            |span| span.style.color == Some(Color::Magenta) && !span.content.trim().is_empty()
+        ));
+    }
+
+    #[test]
+    fn test_stars_move_by_elapsed_time() {
+        let mut feature = StarfieldFeature::new();
+        feature.initialize_stars();
+        for star in &mut feature.stars {
+            star.z = 10.0;
+        }
+
+        feature.update_stars(Duration::from_millis(50));
+        assert!((feature.stars[0].z - 9.0).abs() < 1e-9);
+
+        feature.update_stars(Duration::from_millis(200));
+        assert!((feature.stars[0].z - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_offscreen_stars_are_not_drawn_on_the_edges() {
+        assert_eq!(project(-1.5, 60), None);
+        assert_eq!(project(-0.01, 60), Some(29));
+        assert_eq!(project(-1.0, 60), Some(0));
+        assert_eq!(project(1.0, 60), None);
+
+        let mut feature = StarfieldFeature::new().with_rng(SeededRng::new(7));
+        feature.star_count = 400;
+        let lines = feature.render(Rect::new(0, 0, 60, 16));
+
+        let stars = |text: String| text.chars().filter(|c| *c != ' ').count();
+        assert!(stars(lines[0].content()) < 10);
+        let left_column: String = lines
+            .iter()
+            .take(15)
+            .filter_map(|line| line.content().chars().next())
+            .collect();
+        assert!(stars(left_column) < 4);
+    }
+}
@@ -1,5 +1,4 @@
 pub mod system_info_logic;
 
-// Tests temporarily disabled to focus on main functionality
-// #[cfg(test)]
-// pub mod system_info_logic_tests;
+#[cfg(test)]
+pub mod system_info_logic_tests;
@@ -1,15 +1,15 @@
+use crate::cli::config_logic::SystemInfoConfig;
+use crate::features::{ScreensaverAction, ScreensaverFeature};
+use crate::shared::{Rect, TextLine};
+use crate::styles::theme_logic::Theme;
 use crossterm::event::{KeyCode, KeyEvent};
-use ratatui::{
-    layout::Rect,
-    style::{Color, Style},
-    text::{Line, Span},
-};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 pub struct SystemInfoFeature {
-    content: Vec<String>,
-    last_update: u64,
-    update_interval: u64, // seconds
+    pub content: Vec<String>,
+    pub last_update: u64,
+    pub update_interval: u64, // seconds
+    theme: Theme,
 }
 
 impl Default for SystemInfoFeature {
@@ -24,17 +24,90 @@ impl SystemInfoFeature {
             content: Vec::new(),
             last_update: 0,
             update_interval: 5, // Update every 5 seconds
+            theme: Theme::default(),
         }
     }
 
-    pub fn render(&mut self, area: Rect) -> Vec<Line> {
+    pub fn with_config(config: &SystemInfoConfig) -> Self {
+        Self {
+            update_interval: config.update_interval,
+            ..Self::new()
+        }
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    fn update_system_info(&mut self) {
+        self.content.clear();
+
+        // Current time
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
-            .unwrap()
+            .unwrap_or_default()
+            .as_secs();
+
+        self.content
+            .push(format!("Current Time: {}", format_timestamp(now)));
+
+        // Uptime, memory and load come from /proc and are left out where
+        // it isn't available
+        if let Some(uptime) = read_proc("uptime").as_deref().and_then(parse_uptime) {
+            self.content
+                .push(format!("Uptime: {}", format_uptime(uptime)));
+        }
+
+        if let Some((total, available)) = read_proc("meminfo").as_deref().and_then(parse_meminfo) {
+            self.content.push(format!(
+                "Memory: {} used of {}",
+                format_kib(total.saturating_sub(available)),
+                format_kib(total)
+            ));
+        }
+
+        let cores = std::thread::available_parallelism().map_or(1, |n| n.get());
+        match read_proc("loadavg").as_deref().and_then(parse_loadavg) {
+            Some(load) => self
+                .content
+                .push(format!("CPU: {} cores, load {}", cores, load)),
+            None => self.content.push(format!("CPU: {} cores", cores)),
+        }
+
+        // System name
+        self.content
+            .push(format!("System: {}", std::env::consts::OS));
+        self.content
+            .push(format!("Architecture: {}", std::env::consts::ARCH));
+
+        // Environment variables count
+        let env_count = std::env::vars().count();
+        self.content
+            .push(format!("Environment Variables: {}", env_count));
+
+        // Current directory
+        if let Ok(current_dir) = std::env::current_dir() {
+            self.content
+                .push(format!("Current Dir: {}", current_dir.display()));
+        }
+
+        // Add some spacing
+        self.content.push("".to_string());
+        self.content
+            .push("Press 'r' to refresh, ESC to exit".to_string());
+    }
+}
+
+impl ScreensaverFeature for SystemInfoFeature {
+    fn render(&mut self, area: Rect) -> Vec<TextLine> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
             .as_secs();
 
         // Update system info if interval passed
-        if now - self.last_update >= self.update_interval {
+        if now.saturating_sub(self.last_update) >= self.update_interval {
             self.update_system_info();
             self.last_update = now;
         }
@@ -42,29 +115,30 @@ impl SystemInfoFeature {
         let mut lines = Vec::new();
 
         // System Information Header
-        lines.push(Line::from(vec![Span::styled(
-            "=== SYSTEM INFORMATION ===",
-            Style::default().fg(Color::Cyan),
-        )]));
-        lines.push(Line::from(""));
+        lines.push(TextLine::styled(
+            "=== SYSTEM INFORMATION ===".to_string(),
+            self.theme.accent.clone(),
+        ));
+        lines.push(TextLine::new(String::new()));
 
         // Display collected system info
         for info_line in &self.content {
-            lines.push(Line::from(vec![Span::styled(
-                info_line,
-                Style::default().fg(Color::White),
-            )]));
+            lines.push(TextLine::styled(
+                info_line.clone(),
+                self.theme.primary.clone(),
+            ));
         }
 
         // Pad remaining space
         while lines.len() < area.height as usize {
-            lines.push(Line::from(""));
+            lines.push(TextLine::new(String::new()));
         }
 
+        lines.truncate(area.height as usize);
         lines
     }
 
-    pub fn handle_input(&mut self, key_event: KeyEvent) -> ScreensaverAction {
+    fn handle_input(&mut self, key_event: KeyEvent) -> ScreensaverAction {
         match key_event.code {
             KeyCode::Esc => ScreensaverAction::Exit,
             KeyCode::Enter => ScreensaverAction::NextScreen,
@@ -77,76 +151,84 @@ impl SystemInfoFeature {
         }
     }
 
-    pub fn resize(&mut self, _new_area: Rect) {
+    fn resize(&mut self, _new_area: Rect) {
         // System info doesn't need special resize handling
     }
 
-    fn update_system_info(&mut self) {
-        self.content.clear();
-
-        // Current time
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-
-        let hours = (now / 3600) % 24;
-        let minutes = (now / 60) % 60;
-        let seconds = now % 60;
-
-        self.content.push(format!(
-            "Current Time: {}:{}:{}",
-            format_two_digits(hours),
-            format_two_digits(minutes),
-            format_two_digits(seconds)
-        ));
+    fn name(&self) -> &'static str {
+        "system_info"
+    }
+}
 
-        // Uptime (simplified calculation)
-        self.content.push(format!("Uptime: {} seconds", now));
+pub(crate) fn format_timestamp(timestamp: u64) -> String {
+    let hours = (timestamp / 3600) % 24;
+    let minutes = (timestamp / 60) % 60;
+    let seconds = timestamp % 60;
+
+    format!(
+        "{}:{}:{} UTC",
+        format_two_digits(hours),
+        format_two_digits(minutes),
+        format_two_digits(seconds)
+    )
+}
 
-        // Memory info (placeholder)
-        self.content
-            .push("Memory: Information unavailable".to_string());
+pub(crate) fn format_two_digits(num: u64) -> String {
+    if num < 10 {
+        format!("0{}", num)
+    } else {
+        format!("{}", num)
+    }
+}
 
-        // CPU info (placeholder)
-        self.content
-            .push("CPU: Information unavailable".to_string());
+#[cfg(target_os = "linux")]
+fn read_proc(name: &str) -> Option<String> {
+    std::fs::read_to_string(format!("/proc/{}", name)).ok()
+}
 
-        // System name
-        self.content
-            .push(format!("System: {}", std::env::consts::OS));
-        self.content
-            .push(format!("Architecture: {}", std::env::consts::ARCH));
+#[cfg(not(target_os = "linux"))]
+fn read_proc(_name: &str) -> Option<String> {
+    None
+}
 
-        // Environment variables count
-        let env_count = std::env::vars().count();
-        self.content
-            .push(format!("Environment Variables: {}", env_count));
+// Whole seconds from /proc/uptime ("12345.67 54321.00")
+pub(crate) fn parse_uptime(text: &str) -> Option<u64> {
+    let seconds: f64 = text.split_whitespace().next()?.parse().ok()?;
+    Some(seconds as u64)
+}
 
-        // Current directory
-        if let Ok(current_dir) = std::env::current_dir() {
-            self.content
-                .push(format!("Current Dir: {}", current_dir.display()));
-        }
+// MemTotal and MemAvailable from /proc/meminfo, in KiB
+pub(crate) fn parse_meminfo(text: &str) -> Option<(u64, u64)> {
+    let field = |name: &str| {
+        text.lines()
+            .find_map(|line| line.strip_prefix(name)?.strip_prefix(':'))
+            .and_then(|value| value.split_whitespace().next()?.parse().ok())
+    };
+    Some((field("MemTotal")?, field("MemAvailable")?))
+}
 
-        // Add some spacing
-        self.content.push("".to_string());
-        self.content
-            .push("Press 'r' to refresh, ESC to exit".to_string());
-    }
+// The 1, 5 and 15 minute load averages from /proc/loadavg
+pub(crate) fn parse_loadavg(text: &str) -> Option<String> {
+    let averages: Vec<&str> = text.split_whitespace().take(3).collect();
+    (averages.len() == 3).then(|| averages.join(" "))
 }
 
-#[derive(Debug, PartialEq)]
-pub enum ScreensaverAction {
-    Continue,
-    Exit,
-    NextScreen,
+pub(crate) fn format_uptime(seconds: u64) -> String {
+    let (days, hours, minutes) = (seconds / 86400, (seconds / 3600) % 24, (seconds / 60) % 60);
+    if days > 0 {
+        format!("{}d {}h {}m", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m {}s", minutes, seconds % 60)
+    }
 }
 
-fn format_two_digits(num: u64) -> String {
-    if num < 10 {
-        format!("0{}", num)
+pub(crate) fn format_kib(kib: u64) -> String {
+    let gib = kib as f64 / (1024.0 * 1024.0);
+    if gib >= 1.0 {
+        format!("{:.1} GiB", gib)
     } else {
-        format!("{}", num)
+        format!("{} MiB", kib / 1024)
     }
 }
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod system_info_tests {
+    use crate::cli::config_logic::SystemInfoConfig;
+    use crate::features::system_info::system_info_logic::*;
+    use crate::features::{ScreensaverAction, ScreensaverFeature};
+    use crate::shared::Rect;
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    #[test]
+    fn test_system_info_creation() {
+        let feature = SystemInfoFeature::new();
+        assert_eq!(feature.content.len(), 0);
+        assert_eq!(feature.update_interval, 5);
+
+        let feature = SystemInfoFeature::with_config(&SystemInfoConfig {
+            enabled: true,
+            update_interval: 30,
+        });
+        assert_eq!(feature.update_interval, 30);
+    }
+
+    #[test]
+    fn test_handle_escape_key() {
+        let mut feature = SystemInfoFeature::new();
+        let action = feature.handle_input(KeyEvent::from(KeyCode::Esc));
+        assert_eq!(action, ScreensaverAction::Exit);
+    }
+
+    #[test]
+    fn test_handle_refresh_key() {
+        let mut feature = SystemInfoFeature::new();
+        feature.last_update = 1000;
+        let action = feature.handle_input(KeyEvent::from(KeyCode::Char('r')));
+        assert_eq!(action, ScreensaverAction::Continue);
+        assert_eq!(feature.last_update, 0); // Should reset for refresh
+    }
+
+    #[test]
+    fn test_render_collects_info_and_fills_area() {
+        let mut feature = SystemInfoFeature::new();
+        let lines = feature.render(Rect::new(0, 0, 60, 20));
+
+        assert_eq!(lines.len(), 20);
+        assert_eq!(lines[0].content(), "=== SYSTEM INFORMATION ===");
+        assert!(!feature.content.is_empty());
+        assert!(feature.render(Rect::new(0, 0, 60, 3)).len() <= 3);
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(3661), "01:01:01 UTC");
+        assert_eq!(format_timestamp(0), "00:00:00 UTC");
+        assert_eq!(format_timestamp(3600), "01:00:00 UTC");
+    }
+
+    #[test]
+    fn test_format_two_digits() {
+        assert_eq!(format_two_digits(5), "05");
+        assert_eq!(format_two_digits(15), "15");
+        assert_eq!(format_two_digits(0), "00");
+    }
+
+    #[test]
+    fn test_proc_files_are_parsed() {
+        assert_eq!(parse_uptime("93784.52 180000.10\n"), Some(93784));
+        assert_eq!(parse_uptime(""), None);
+
+        let meminfo = "MemTotal:       16303428 kB\nMemFree:         1200000 kB\nMemAvailable:    8151714 kB\n";
+        assert_eq!(parse_meminfo(meminfo), Some((16303428, 8151714)));
+        assert_eq!(parse_meminfo("MemTotal: 100 kB\n"), None);
+
+        assert_eq!(
+            parse_loadavg("0.52 0.61 0.70 1/523 4242\n"),
+            Some("0.52 0.61 0.70".to_string())
+        );
+        assert_eq!(parse_loadavg("0.52"), None);
+    }
+
+    #[test]
+    fn test_uptime_and_memory_are_formatted() {
+        assert_eq!(format_uptime(93784), "1d 2h 3m");
+        assert_eq!(format_uptime(3720), "1h 2m");
+        assert_eq!(format_uptime(75), "1m 15s");
+
+        assert_eq!(format_kib(8151714), "7.8 GiB");
+        assert_eq!(format_kib(524288), "512 MiB");
+    }
+
+    #[test]
+    fn test_no_placeholder_lines() {
+        let mut feature = SystemInfoFeature::new();
+        feature.render(Rect::new(0, 0, 60, 20));
+
+        assert!(feature
+            .content
+            .iter()
+            .all(|line| !line.contains("unavailable")));
+        assert!(feature.content.iter().any(|line| line.starts_with("CPU: ")));
+    }
+}
@@ -1,4 +1,4 @@
 pub mod text_display_logic;
 
-// Tests temporarily disabled to focus on main functionality
-// pub mod text_display_logic_tests;
+#[cfg(test)]
+pub mod text_display_logic_tests;
@@ -1,140 +1,203 @@
 use crate::cli::cli_logic::{ActionConfig, Config};
-use crate::shared::shared_logic as shared;
-use crate::shared::{SimpleRenderer, TextLine};
-use crossterm::event::{read, Event, KeyCode, KeyEvent};
-use crossterm::style::Color;
-use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
-use std::io::Read;
-use std::process::Command;
-
-pub fn run_screensaver(config: Config) {
-    enable_raw_mode().unwrap();
-    let mut renderer = SimpleRenderer::new().unwrap();
-    let show_help = true; // Help always visible
-    shared::clear_screen();
-
-    loop {
-        renderer.update_size().unwrap();
-        let (width, height) = renderer.get_size();
-
-        // Create text display with optional help
-        let lines = create_text_display(&config.text, &config.actions, width, height, show_help);
-        renderer.render_lines(lines).unwrap();
-
-        if let Event::Key(KeyEvent { code, .. }) = read().unwrap() {
-            match code {
-                KeyCode::Esc => break,
-                KeyCode::Enter => {
-                    // Show action menu if actions are available
-                    if !config.actions.is_empty() {
-                        execute_action_menu(&config.actions);
-                        shared::clear_screen();
-                    } else {
-                        log::info!("Enter pressed, but no actions configured");
-                    }
-                }
-                KeyCode::Char(c) => {
-                    // Check if this character matches any configured action key
-                    if let Some(action) = config
-                        .actions
-                        .iter()
-                        .find(|a| a.key.to_lowercase() == c.to_string().to_lowercase())
-                    {
-                        execute_script(&action.command);
-                        shared::clear_screen();
-                    }
+use crate::cli::config_logic::{ActionOutput, TextAlign};
+use crate::features::{ScreensaverAction, ScreensaverFeature};
+use crate::shared::text_width::{display_width, grapheme_width, graphemes, wrap_text};
+use crate::shared::{run_action, ActionMenu, MenuEvent, OutputPane, Rect, TextLine};
+use crate::styles::style_logic::{get_themed_style, Style};
+use crate::styles::theme_logic::Theme;
+use chrono::Local;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::time::Instant;
+
+pub struct TextDisplayFeature {
+    text: String,
+    style: String,
+    align: TextAlign,
+    actions: Vec<ActionConfig>,
+    show_help: bool,
+    theme: Theme,
+    started: Instant,
+    menu: Option<ActionMenu>,
+    pane: Option<OutputPane>,
+}
+
+impl TextDisplayFeature {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            text: config.display_text().to_string(),
+            style: config.display_style().to_string(),
+            align: config.features.text_display.align,
+            actions: config.actions.clone(),
+            show_help: true, // Help always visible
+            theme: Theme::default(),
+            started: Instant::now(),
+            menu: None,
+            pane: None,
+        }
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub fn is_menu_open(&self) -> bool {
+        self.menu.is_some()
+    }
+
+    pub fn is_pane_open(&self) -> bool {
+        self.pane.is_some()
+    }
+
+    // Pane actions draw through the renderer; terminal actions take over the
+    // screen, so the runtime is asked to repaint afterwards
+    fn run(&mut self, action: &ActionConfig) -> ScreensaverAction {
+        match action.output {
+            ActionOutput::Pane => {
+                self.pane = Some(OutputPane::open(action.clone()).with_theme(self.theme.clone()));
+                ScreensaverAction::Continue
+            }
+            ActionOutput::Terminal => {
+                if let Err(e) = run_action(action) {
+                    log::error!("Action '{}' failed: {}", action.description, e);
                 }
-                _ => {}
+                ScreensaverAction::Repaint
             }
         }
     }
-
-    disable_raw_mode().unwrap();
 }
 
-fn execute_script(command: &str) {
-    disable_raw_mode().unwrap();
-
-    log::info!("Executing script: {}", command);
-
-    // Clear screen, move cursor to top, and ensure output is flushed
-    print!("\x1b[2J\x1b[1;1H");
-    print!("\x1b[?25h"); // Show cursor
-
-    let status = Command::new("bash")
-        .arg("-c")
-        .arg(command)
-        .stdin(std::process::Stdio::inherit())
-        .stdout(std::process::Stdio::inherit())
-        .stderr(std::process::Stdio::inherit())
-        .status();
-
-    match status {
-        Ok(exit_status) => {
-            if exit_status.success() {
-                log::info!("Script executed successfully");
-            } else {
-                log::error!("Script execution failed with status: {}", exit_status);
-            }
+impl ScreensaverFeature for TextDisplayFeature {
+    fn render(&mut self, area: Rect) -> Vec<TextLine> {
+        // Re-rendered every frame, so animated styles and the clock stay live
+        let elapsed = self.started.elapsed();
+        let clock = Local::now().format("%H:%M:%S").to_string();
+        let help = HelpPanel {
+            actions: &self.actions,
+            clock: Some(&clock),
+            theme: &self.theme,
+        };
+        let mut lines = create_text_display(
+            &self.text,
+            self.align,
+            area.width,
+            area.height,
+            self.show_help.then_some(&help),
+            &|index| get_themed_style(&self.style, &self.theme, elapsed, index),
+        );
+        if let Some(menu) = &self.menu {
+            menu.overlay(&mut lines, &area);
         }
-        Err(e) => {
-            log::error!("Failed to execute script: {}", e);
-            println!("Error executing script: {}", e);
+        if let Some(pane) = self.pane.as_mut() {
+            pane.refresh();
+            pane.overlay(&mut lines, &area);
         }
+        lines
     }
 
-    // Wait for user input before returning to screensaver
-    println!("\nPress any key to return to screensaver...");
-    let mut buffer = [0u8; 1];
-    let _ = std::io::stdin().read(&mut buffer);
-
-    print!("\x1b[?25l"); // Hide cursor again
-
-    enable_raw_mode().unwrap();
-}
+    fn handle_input(&mut self, key_event: KeyEvent) -> ScreensaverAction {
+        // While the output pane or the menu is open it gets every key
+        if let Some(pane) = self.pane.as_mut() {
+            if pane.handle_key(key_event) {
+                self.pane = None;
+            }
+            return ScreensaverAction::Continue;
+        }
+        if let Some(menu) = self.menu.as_mut() {
+            match menu.handle_key(key_event) {
+                MenuEvent::Continue => {}
+                MenuEvent::Close => self.menu = None,
+                MenuEvent::Run(action) => {
+                    self.menu = None;
+                    return self.run(&action);
+                }
+            }
+            return ScreensaverAction::Continue;
+        }
 
-fn execute_action_menu(actions: &[ActionConfig]) {
-    disable_raw_mode().unwrap();
+        match key_event.code {
+            KeyCode::Esc => return ScreensaverAction::Exit,
+            KeyCode::Enter => {
+                // Show action menu if actions are available
+                if !self.actions.is_empty() {
+                    self.menu =
+                        Some(ActionMenu::new(self.actions.clone()).with_theme(self.theme.clone()));
+                } else {
+                    log::info!("Enter pressed, but no actions configured");
+                }
+            }
+            KeyCode::Char(c) => {
+                // Check if this character matches any configured action key
+                if let Some(action) = self
+                    .actions
+                    .iter()
+                    .find(|a| a.key.to_lowercase() == c.to_string().to_lowercase())
+                    .cloned()
+                {
+                    return self.run(&action);
+                }
+            }
+            _ => {}
+        }
+        ScreensaverAction::Continue
+    }
 
-    println!("\n=== Available Actions ===");
-    for (i, action) in actions.iter().enumerate() {
-        println!("{}. {} (Press '{}')", i + 1, action.description, action.key);
+    fn resize(&mut self, _new_area: Rect) {
+        // Text is re-wrapped around the help panel on every render
     }
-    println!("ESC. Return to screensaver");
 
-    println!("\nPress any key to continue...");
-    let _ = std::io::Read::read(&mut std::io::stdin(), &mut [0u8; 1]);
+    fn name(&self) -> &'static str {
+        "text_display"
+    }
+}
 
-    enable_raw_mode().unwrap();
+// What the help panel in the top right corner lists and how it is drawn
+pub(crate) struct HelpPanel<'a> {
+    pub actions: &'a [ActionConfig],
+    pub clock: Option<&'a str>,
+    pub theme: &'a Theme,
 }
 
-fn create_text_display(
+pub(crate) fn create_text_display(
     text: &str,
-    actions: &[ActionConfig],
+    align: TextAlign,
     width: u16,
     height: u16,
-    show_help: bool,
+    help: Option<&HelpPanel>,
+    text_style: &dyn Fn(usize) -> Style,
 ) -> Vec<TextLine> {
     let mut lines = Vec::new();
 
-    // Define base help commands
-    let mut help_commands = vec!["ESC - Exit screensaver".to_string()];
+    let show_help = help.is_some();
+    let actions = help.map(|help| help.actions).unwrap_or_default();
+    let clock = help.and_then(|help| help.clock);
+    let theme = help.map(|help| help.theme.clone()).unwrap_or_default();
+    let border_style = theme.help_border.clone();
+    let key_style = theme.help_text.clone().bold();
+    let description_style = theme.help_text.clone();
+    let blank = Style::new();
+
+    // Define base help commands as (key, description) pairs
+    let mut help_commands = vec![("ESC".to_string(), "Exit screensaver".to_string())];
 
     // Add configured actions to help
     for action in actions {
-        help_commands.push(format!(
-            "{} - {}",
-            action.key.to_uppercase(),
-            action.description
-        ));
+        help_commands.push((action.key.to_uppercase(), action.description.clone()));
     }
 
     // Add additional commands if we have actions
     if !actions.is_empty() {
-        help_commands.push("ENTER - Show action menu".to_string());
-    } // Calculate help panel dimensions only if showing help
+        help_commands.push(("ENTER".to_string(), "Show action menu".to_string()));
+    }
+
+    // Calculate help panel dimensions only if showing help
     let (help_width, help_height, help_start_x) = if show_help {
-        let help_w = help_commands.iter().map(|cmd| cmd.len()).max().unwrap_or(0) + 4;
+        let help_w = help_commands
+            .iter()
+            .map(|(key, description)| display_width(key) + display_width(description) + 3)
+            .max()
+            .unwrap_or(0)
+            + 4;
         let help_h = help_commands.len() + 2; // +2 for border
         let help_x = if width > help_w as u16 + 2 {
             width - help_w as u16 - 1
@@ -146,92 +209,107 @@ fn create_text_display(
         (0, 0, width) // No help panel
     };
 
+    // Main text, wrapped to the width kept clear of the help panel and
+    // centered vertically as a block
+    let available_width = if help_start_x > 0 && show_help {
+        help_start_x as usize - 2
+    } else {
+        width as usize
+    };
+    let mut rows = wrap_text(text, available_width);
+    rows.truncate(height as usize);
+    let top = (height as usize + 1).saturating_sub(rows.len()) / 2;
+    // Styles are indexed across the whole block so animations flow between rows
+    let mut first_index = 0;
+
     // Add lines for the display
     for y in 0..height {
-        let mut line_content = String::new();
-        let mut line_color = None;
+        let mut line = TextLine::default();
 
         // Check if we're in the help panel area (top right)
         let is_help_area = show_help && y < help_height as u16 && help_start_x > 0;
 
+        let row = (y as usize).checked_sub(top).and_then(|i| rows.get(i));
+        let main_text = row.map(|row| {
+            let offset = first_index;
+            first_index += graphemes(row).count();
+            let spare = available_width.saturating_sub(display_width(row));
+            let padding = match align {
+                TextAlign::Left => 0,
+                TextAlign::Center => spare / 2,
+                TextAlign::Right => spare,
+            };
+            (row.as_str(), padding, offset)
+        });
+        let styled = |offset: usize| move |index: usize| text_style(offset + index);
+
         if is_help_area {
-            // Create help panel content
-            if y == 0 {
-                // Top border
-                line_content = format!(
-                    "{}┌{}┐",
-                    " ".repeat(help_start_x as usize),
-                    "─".repeat(help_width - 2)
-                );
-                line_color = Some(Color::Cyan);
-            } else if y == help_height as u16 - 1 {
-                // Bottom border
-                line_content = format!(
-                    "{}└{}┘",
-                    " ".repeat(help_start_x as usize),
-                    "─".repeat(help_width - 2)
-                );
-                line_color = Some(Color::Cyan);
-            } else {
-                // Help command lines
-                let cmd_index = y as usize - 1;
-                if cmd_index < help_commands.len() {
-                    let cmd = &help_commands[cmd_index];
-                    let padding = help_width - cmd.len() - 3; // -3 for borders and space
-                    line_content = format!(
-                        "{}│ {}{}│",
-                        " ".repeat(help_start_x as usize),
-                        cmd,
-                        " ".repeat(padding)
-                    );
-                    line_color = Some(Color::Yellow);
-                }
+            // Fill the space left of the panel, with the main text if it lands here
+            let prefix_width = help_start_x as usize;
+            let mut used = 0;
+            if let Some((row, padding, offset)) = main_text {
+                used = push_main_text(&mut line, row, padding, prefix_width - 1, &styled(offset));
             }
-        }
-
-        // Add main text content (centered)
-        if y == height / 2 {
-            // Center the main text horizontally, but avoid help area
-            let available_width = if help_start_x > 0 && show_help {
-                help_start_x as usize - 2
-            } else {
-                width as usize
-            };
+            line.push_styled(&" ".repeat(prefix_width - used), &blank);
 
-            if text.len() < available_width {
-                let padding = (available_width - text.len()) / 2;
-                let main_text = format!("{}{}", " ".repeat(padding), text);
-
-                // Combine main text with help panel if both exist
-                if is_help_area {
-                    // Ensure main text doesn't overlap with help
-                    let safe_text_len = std::cmp::min(main_text.len(), help_start_x as usize - 1);
-                    if safe_text_len > 0 {
-                        line_content = format!(
-                            "{}{}",
-                            &main_text[..safe_text_len],
-                            &line_content[safe_text_len..]
+            if y == 0 {
+                // Top border, titled with the clock when there is room for it
+                let inner = help_width - 2;
+                match clock.filter(|clock| display_width(clock) + 4 <= inner) {
+                    Some(clock) => {
+                        line.push_styled("┌─ ", &border_style);
+                        line.push_styled(clock, &description_style);
+                        line.push_styled(
+                            &format!(" {}┐", "─".repeat(inner - display_width(clock) - 3)),
+                            &border_style,
                         );
                     }
-                    // Keep help color for the help portion
-                } else {
-                    line_content = main_text;
-                    line_color = Some(Color::Green);
+                    None => line.push_styled(&format!("┌{}┐", "─".repeat(inner)), &border_style),
                 }
+            } else if y == help_height as u16 - 1 {
+                // Bottom border
+                line.push_styled(&format!("└{}┘", "─".repeat(help_width - 2)), &border_style);
+            } else if let Some((key, description)) = help_commands.get(y as usize - 1) {
+                // Help command lines with the key highlighted
+                let cmd_len = display_width(key) + display_width(description) + 3;
+                let padding = help_width - cmd_len - 3; // -3 for borders and space
+                line.push_styled("│ ", &border_style);
+                line.push_styled(key, &key_style);
+                line.push_styled(&format!(" - {}", description), &description_style);
+                line.push_styled(&" ".repeat(padding), &blank);
+                line.push_styled("│", &border_style);
             }
+        } else if let Some((row, padding, offset)) = main_text {
+            push_main_text(&mut line, row, padding, width as usize, &styled(offset));
         }
 
-        // Create the TextLine
-        if line_content.is_empty() {
-            lines.push(TextLine::new(String::new()));
-        } else {
-            lines.push(if let Some(color) = line_color {
-                TextLine::with_color(line_content, color)
-            } else {
-                TextLine::new(line_content)
-            });
-        }
+        lines.push(line);
     }
 
     lines
 }
+
+// Push padding plus the main text, styling each character by its index and
+// stopping before a glyph would cross `limit` columns; returns the number of
+// columns used
+fn push_main_text(
+    line: &mut TextLine,
+    text: &str,
+    padding: usize,
+    limit: usize,
+    text_style: &dyn Fn(usize) -> Style,
+) -> usize {
+    let padding = padding.min(limit);
+    line.push_styled(&" ".repeat(padding), &Style::new());
+
+    let mut used = padding;
+    for (index, grapheme) in graphemes(text).enumerate() {
+        let width = grapheme_width(grapheme);
+        if used + width > limit {
+            break;
+        }
+        line.push_styled(grapheme, &text_style(index));
+        used += width;
+    }
+    used
+}
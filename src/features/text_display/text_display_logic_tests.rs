@@ -0,0 +1,208 @@
+#[cfg(test)]
+mod text_display_tests {
+    use crate::cli::cli_logic::{ActionConfig, Config};
+    use crate::cli::config_logic::TextAlign;
+    use crate::features::text_display::text_display_logic::*;
+    use crate::features::{ScreensaverAction, ScreensaverFeature};
+    use crate::shared::Rect;
+    use crate::styles::style_logic::Style;
+    use crate::styles::theme_logic::Theme;
+    use crossterm::event::{KeyCode, KeyEvent};
+    use crossterm::style::{Attribute, Color};
+
+    fn green() -> Style {
+        Style::new().fg(Color::Green)
+    }
+
+    fn help<'a>(
+        actions: &'a [ActionConfig],
+        clock: Option<&'a str>,
+        theme: &'a Theme,
+    ) -> HelpPanel<'a> {
+        HelpPanel {
+            actions,
+            clock,
+            theme,
+        }
+    }
+
+    fn actions() -> Vec<ActionConfig> {
+        vec![ActionConfig {
+            key: "u".to_string(),
+            description: "Update system".to_string(),
+            command: "true".to_string(),
+            ..Default::default()
+        }]
+    }
+
+    #[test]
+    fn test_config_creation() {
+        let config = Config {
+            text: "Test".to_string(),
+            style: "default".to_string(),
+            ..Default::default()
+        };
+        let mut feature = TextDisplayFeature::new(&config);
+        let lines = feature.render(Rect::new(0, 0, 80, 24));
+
+        assert_eq!(lines.len(), 24);
+        assert!(lines[12].content().contains("Test"));
+    }
+
+    #[test]
+    fn test_escape_exits() {
+        let mut feature = TextDisplayFeature::new(&Config::default());
+        assert_eq!(
+            feature.handle_input(KeyEvent::from(KeyCode::Esc)),
+            ScreensaverAction::Exit
+        );
+    }
+
+    #[test]
+    fn test_menu_keys_do_not_request_a_repaint() {
+        let config = Config {
+            actions: actions(),
+            ..Default::default()
+        };
+        let mut feature = TextDisplayFeature::new(&config);
+
+        // Only actions run in the terminal draw outside the renderer
+        for code in [
+            KeyCode::Enter,
+            KeyCode::Down,
+            KeyCode::Char('u'),
+            KeyCode::Esc,
+            KeyCode::Esc,
+        ] {
+            assert_eq!(
+                feature.handle_input(KeyEvent::from(code)),
+                ScreensaverAction::Continue
+            );
+        }
+        assert!(!feature.is_menu_open());
+    }
+
+    #[test]
+    fn test_help_panel_highlights_keys() {
+        let (actions, theme) = (actions(), Theme::default());
+        let panel = help(&actions, None, &theme);
+        let lines = create_text_display("Hello", TextAlign::Center, 80, 24, Some(&panel), &|_| {
+            green()
+        });
+
+        let action_row = &lines[2];
+        assert!(action_row.content().contains("U - Update system"));
+        let key_span = action_row
+            .spans
+            .iter()
+            .find(|span| span.content == "U")
+            .expect("key span");
+        assert_eq!(key_span.style.color, Some(Color::Yellow));
+        assert!(key_span.style.attributes.contains(&Attribute::Bold));
+
+        let border = lines[0].spans.last().unwrap();
+        assert!(border.content.starts_with('┌'));
+        assert_eq!(border.style.color, Some(Color::Cyan));
+    }
+
+    #[test]
+    fn test_main_text_is_styled_and_centered() {
+        let lines = create_text_display("Hello", TextAlign::Center, 40, 10, None, &|_| green());
+
+        assert_eq!(lines[5].content().trim_start(), "Hello");
+        assert_eq!(lines[5].spans[1].content, "Hello");
+        assert_eq!(lines[5].spans[1].style.color, Some(Color::Green));
+    }
+
+    #[test]
+    fn test_wide_text_is_centered_by_display_width() {
+        let lines =
+            create_text_display("\u{65E5}\u{672C}", TextAlign::Center, 10, 3, None, &|_| {
+                green()
+            });
+
+        assert_eq!(lines[1].content(), "   \u{65E5}\u{672C}");
+    }
+
+    #[test]
+    fn test_multi_line_text_is_centered_as_a_block() {
+        let lines = create_text_display("one\nthree", TextAlign::Center, 11, 6, None, &|_| green());
+
+        assert_eq!(lines[2].content(), "    one");
+        assert_eq!(lines[3].content(), "   three");
+        assert!(lines[1].content().is_empty());
+        assert!(lines[4].content().is_empty());
+    }
+
+    #[test]
+    fn test_lines_are_aligned_left_or_right() {
+        let left = create_text_display("ab\nabcd", TextAlign::Left, 8, 2, None, &|_| green());
+        assert_eq!(left[0].content(), "ab");
+        assert_eq!(left[1].content(), "abcd");
+
+        let right = create_text_display("ab\nabcd", TextAlign::Right, 8, 2, None, &|_| green());
+        assert_eq!(right[0].content(), "      ab");
+        assert_eq!(right[1].content(), "    abcd");
+    }
+
+    #[test]
+    fn test_long_text_wraps_clear_of_the_help_panel() {
+        let (actions, theme) = (actions(), Theme::default());
+        let panel = help(&actions, None, &theme);
+        let text = "a status board for the build farm in the basement";
+        let lines =
+            create_text_display(text, TextAlign::Center, 60, 12, Some(&panel), &|_| green());
+
+        let panel_left = lines[0].content().chars().position(|c| c == '┌').unwrap();
+        let text_rows: Vec<String> = lines
+            .iter()
+            .map(|line| line.content().chars().take(panel_left).collect::<String>())
+            .map(|row| row.trim().to_string())
+            .filter(|row| !row.is_empty())
+            .collect();
+        assert!(text_rows.len() > 1);
+        assert_eq!(text_rows.join(" "), text);
+    }
+
+    #[test]
+    fn test_styles_continue_across_wrapped_rows() {
+        let lines = create_text_display("ab\ncd", TextAlign::Left, 4, 2, None, &|index| {
+            Style::new().fg([Color::Red, Color::Blue, Color::Green, Color::Yellow][index])
+        });
+
+        assert_eq!(lines[1].spans[1].style.color, Some(Color::Green));
+    }
+
+    #[test]
+    fn test_text_style_is_applied_per_character() {
+        let styles = [Color::Red, Color::Blue];
+        let lines = create_text_display("abcd", TextAlign::Center, 20, 3, None, &|index| {
+            Style::new().fg(styles[index % 2])
+        });
+
+        let colored: Vec<(String, Option<Color>)> = lines[1]
+            .spans
+            .iter()
+            .skip(1)
+            .map(|span| (span.content.clone(), span.style.color))
+            .collect();
+        assert_eq!(colored.len(), 4);
+        assert_eq!(colored[0], ("a".to_string(), Some(Color::Red)));
+        assert_eq!(colored[1], ("b".to_string(), Some(Color::Blue)));
+    }
+
+    #[test]
+    fn test_help_panel_shows_clock_in_top_border() {
+        let (actions, theme) = (actions(), Theme::default());
+        let panel = help(&actions, Some("12:34:56"), &theme);
+        let lines = create_text_display("Hello", TextAlign::Center, 80, 24, Some(&panel), &|_| {
+            green()
+        });
+
+        assert!(lines[0].content().contains("┌─ 12:34:56 ─"));
+        assert!(lines[0].content().ends_with('┐'));
+        // Every panel row keeps the same width
+        let width = lines[0].content().chars().count();
+        assert_eq!(lines[1].content().chars().count(), width);
+    }
+}
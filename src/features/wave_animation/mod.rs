@@ -1,5 +1,4 @@
 pub mod wave_animation_logic;
 
-// Tests temporarily disabled to focus on main functionality
-// #[cfg(test)]
-// pub mod wave_animation_logic_tests;
+#[cfg(test)]
+pub mod wave_animation_logic_tests;
@@ -1,19 +1,19 @@
+use crate::cli::config_logic::WaveAnimationConfig;
+use crate::features::{frame_steps, ScreensaverAction, ScreensaverFeature};
+use crate::shared::text_width::center_text;
+use crate::shared::{Rect, TextLine};
+use crate::styles::theme_logic::Theme;
 use crossterm::event::{KeyCode, KeyEvent};
-use ratatui::{
-    layout::Rect,
-    style::{Color, Style},
-    text::{Line, Span},
-};
 use std::f64::consts::PI;
+use std::time::Duration;
 
 pub struct WaveAnimationFeature {
-    time: f64,
-    speed: f64,
-    amplitude: f64,
-    frequency: f64,
-    #[allow(dead_code)]
-    phase_shift: f64,
-    wave_chars: Vec<char>,
+    pub time: f64,
+    pub speed: f64,
+    pub amplitude: f64,
+    pub frequency: f64,
+    pub wave_chars: Vec<char>,
+    theme: Theme,
 }
 
 impl Default for WaveAnimationFeature {
@@ -29,13 +29,33 @@ impl WaveAnimationFeature {
             speed: 0.1,
             amplitude: 8.0,
             frequency: 0.3,
-            phase_shift: 0.0,
             wave_chars: vec!['~', '`', '^', '*', '°', '·', ' '],
+            theme: Theme::default(),
         }
     }
 
-    pub fn render(&mut self, area: Rect) -> Vec<Line> {
-        self.time += self.speed;
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub fn with_config(config: &WaveAnimationConfig) -> Self {
+        // Config speed is a multiplier on the built-in 0.1 step per frame
+        Self {
+            speed: 0.1 * config.speed,
+            amplitude: config.amplitude as f64,
+            ..Self::new()
+        }
+    }
+}
+
+impl ScreensaverFeature for WaveAnimationFeature {
+    fn tick(&mut self, dt: Duration) {
+        // Speed is the phase advance per reference frame
+        self.time += self.speed * frame_steps(dt);
+    }
+
+    fn render(&mut self, area: Rect) -> Vec<TextLine> {
         let mut lines = Vec::new();
 
         let center_y = area.height as f64 / 2.0;
@@ -43,11 +63,11 @@ impl WaveAnimationFeature {
 
         // Title
         if area.height > 5 {
-            lines.push(Line::from(vec![Span::styled(
+            lines.push(TextLine::styled(
                 center_text("ASCII Wave Animation", width),
-                Style::default().fg(Color::Cyan),
-            )]));
-            lines.push(Line::from(""));
+                self.theme.accent.clone(),
+            ));
+            lines.push(TextLine::new(String::new()));
         }
 
         // Generate wave lines
@@ -90,31 +110,30 @@ impl WaveAnimationFeature {
                 wave_line.push(self.wave_chars[char_index.min(self.wave_chars.len() - 1)]);
             }
 
-            // Color the wave based on position
-            let color = if y as f64 > center_y {
-                Color::Blue
+            // The crest is drawn in the accent color and the trough dimmed
+            let style = if y as f64 > center_y {
+                self.theme.accent.clone().dim()
             } else {
-                Color::Cyan
+                self.theme.accent.clone()
             };
 
-            lines.push(Line::from(vec![Span::styled(
-                wave_line,
-                Style::default().fg(color),
-            )]));
+            lines.push(TextLine::styled(wave_line, style));
         }
 
         // Add controls at bottom if there's space
-        if lines.len() < area.height as usize - 1 {
-            lines.push(Line::from(vec![Span::styled(
-                center_text("Press ESC to exit | SPACE to change wave", width),
-                Style::default().fg(Color::Gray),
-            )]));
+        if area.height > 2 {
+            if let Some(last_line) = lines.last_mut() {
+                *last_line = TextLine::styled(
+                    center_text("Press ESC to exit | SPACE to change wave", width),
+                    self.theme.dim.clone(),
+                );
+            }
         }
 
         lines
     }
 
-    pub fn handle_input(&mut self, key_event: KeyEvent) -> ScreensaverAction {
+    fn handle_input(&mut self, key_event: KeyEvent) -> ScreensaverAction {
         match key_event.code {
             KeyCode::Esc => ScreensaverAction::Exit,
             KeyCode::Enter => ScreensaverAction::NextScreen,
@@ -139,24 +158,11 @@ impl WaveAnimationFeature {
         }
     }
 
-    pub fn resize(&mut self, _new_area: Rect) {
+    fn resize(&mut self, _new_area: Rect) {
         // Wave automatically adapts to new dimensions during render
     }
-}
-
-#[derive(Debug, PartialEq)]
-pub enum ScreensaverAction {
-    Continue,
-    Exit,
-    NextScreen,
-}
 
-fn center_text(text: &str, width: usize) -> String {
-    let text_len = text.len();
-    if text_len >= width {
-        text.to_string()
-    } else {
-        let padding = (width - text_len) / 2;
-        format!("{}{}", " ".repeat(padding), text)
+    fn name(&self) -> &'static str {
+        "wave_animation"
     }
 }
@@ -1,6 +1,12 @@
 #[cfg(test)]
 mod wave_animation_tests {
-    use super::*;
+    use crate::cli::config_logic::WaveAnimationConfig;
+    use crate::features::wave_animation::wave_animation_logic::*;
+    use crate::features::{ScreensaverAction, ScreensaverFeature};
+    use crate::shared::text_width::center_text;
+    use crate::shared::Rect;
+    use crossterm::event::{KeyCode, KeyEvent};
+    use std::time::Duration;
 
     #[test]
     fn test_wave_animation_creation() {
@@ -12,6 +18,17 @@ mod wave_animation_tests {
         assert_eq!(feature.wave_chars.len(), 7);
     }
 
+    #[test]
+    fn test_wave_animation_with_config() {
+        let feature = WaveAnimationFeature::with_config(&WaveAnimationConfig {
+            enabled: true,
+            speed: 2.0,
+            amplitude: 4,
+        });
+        assert_eq!(feature.speed, 0.2);
+        assert_eq!(feature.amplitude, 4.0);
+    }
+
     #[test]
     fn test_handle_escape_key() {
         let mut feature = WaveAnimationFeature::new();
@@ -60,9 +77,21 @@ mod wave_animation_tests {
     fn test_render_produces_lines() {
         let mut feature = WaveAnimationFeature::new();
         let area = Rect::new(0, 0, 40, 20);
-        let lines = feature.render(area);
+        let lines = feature.render(area.clone());
+
+        assert_eq!(lines.len(), area.height as usize);
+        assert!(lines[19].content().contains("ESC"));
+        assert!(feature.render(Rect::new(0, 0, 40, 1)).len() <= 1);
+    }
+
+    #[test]
+    fn test_tick_advances_time_by_elapsed_frames() {
+        let mut feature = WaveAnimationFeature::new();
+
+        feature.tick(Duration::from_millis(50));
+        assert!((feature.time - 0.1).abs() < 1e-9);
 
-        assert!(!lines.is_empty());
-        assert!(lines.len() <= area.height as usize);
+        feature.tick(Duration::from_millis(25));
+        assert!((feature.time - 0.15).abs() < 1e-9);
     }
 }
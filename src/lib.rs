@@ -12,6 +12,7 @@
 //!     text: "Welcome to My App".to_string(),
 //!     style: "default".to_string(),
 //!     actions: vec![],
+//!     ..Default::default()
 //! };
 //!
 //! // Config is available for use with screensaver features
@@ -27,12 +28,8 @@ pub mod styles;
 // Re-export commonly used items
 pub use cli::cli_logic::Config;
 pub use features::{
-    get_available_features,
-    get_feature_description,
-    // Temporarily disabled complex features
-    // TextDisplayFeature, MatrixRainFeature, ClockDisplayFeature,
-    // SystemInfoFeature, WaveAnimationFeature, StarfieldFeature,
-    BouncingLogoFeature,
-    ScreensaverAction,
-    ScreensaverFeature,
+    create_feature, get_available_features, get_feature_description, BouncingLogoFeature,
+    ClockDisplayFeature, MatrixRainFeature, ScreensaverAction, ScreensaverFeature,
+    StarfieldFeature, SystemInfoFeature, TextDisplayFeature, WaveAnimationFeature,
 };
+pub use shared::{ScreensaverError, ScreensaverRuntime};
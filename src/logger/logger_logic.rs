@@ -1,19 +1,124 @@
+use crate::cli::config_logic::{LogFormat, LoggingConfig};
+use crate::shared::{terminal_guard, ScreensaverError};
+use log::{Metadata, Record};
 use simplelog::*;
-use std::fs::File;
+use std::fs::OpenOptions;
+use std::path::{Path, PathBuf};
 
-pub fn init() {
-    CombinedLogger::init(vec![
-        TermLogger::new(
-            LevelFilter::Info,
-            Config::default(),
+const LOG_FILE_NAME: &str = "screensaver.log";
+
+// Log to the configured log file, unless turned off, and print warnings and
+// errors to the terminal
+pub fn init(settings: &LoggingConfig) -> Result<(), ScreensaverError> {
+    let level = settings.level.filter();
+    let config = log_format(settings.format);
+
+    let mut loggers: Vec<Box<dyn SharedLogger>> =
+        vec![Box::new(QuietWhileDrawing(TermLogger::new(
+            terminal_level(level),
+            config.clone(),
             TerminalMode::Mixed,
             ColorChoice::Auto,
-        ),
-        WriteLogger::new(
-            LevelFilter::Info,
-            Config::default(),
-            File::create("screensaver.log").unwrap(),
-        ),
-    ])
-    .unwrap();
+        )))];
+    let path = resolve_log_file(
+        settings.file.as_deref(),
+        std::env::var("XDG_STATE_HOME").ok(),
+        std::env::var("HOME").ok(),
+    );
+    if let Some(path) = &path {
+        loggers.push(WriteLogger::new(level, config, open_log_file(path)?));
+    }
+
+    CombinedLogger::init(loggers).map_err(|e| ScreensaverError::Logger(e.to_string()))?;
+    if let Some(path) = path {
+        log::debug!("Logging to {}", path.display());
+    }
+    Ok(())
+}
+
+// The terminal only gets warnings and errors, so a normal launch prints
+// nothing into the shell; more detailed levels go to the log file alone
+pub(crate) fn terminal_level(level: LevelFilter) -> LevelFilter {
+    level.min(LevelFilter::Warn)
+}
+
+// Where the log file goes: nowhere for "none", the given path (with `~/`
+// expanded) if one is set, otherwise the XDG state directory
+pub(crate) fn resolve_log_file(
+    setting: Option<&str>,
+    xdg_state_home: Option<String>,
+    home: Option<String>,
+) -> Option<PathBuf> {
+    match setting {
+        Some("none") => None,
+        Some(path) => match (path.strip_prefix("~/"), home) {
+            (Some(rest), Some(home)) => Some(Path::new(&home).join(rest)),
+            _ => Some(PathBuf::from(path)),
+        },
+        None => {
+            // Relative XDG paths are invalid per the spec and are ignored
+            let state_dir = match (xdg_state_home, home) {
+                (Some(dir), _) if Path::new(&dir).is_absolute() => PathBuf::from(dir),
+                (_, Some(home)) => Path::new(&home).join(".local/state"),
+                _ => return None,
+            };
+            Some(state_dir.join("terminal-screensaver").join(LOG_FILE_NAME))
+        }
+    }
+}
+
+pub(crate) fn log_format(format: LogFormat) -> Config {
+    match format {
+        LogFormat::Timestamped => Config::default(),
+        LogFormat::Plain => ConfigBuilder::new()
+            .set_time_level(LevelFilter::Off)
+            .build(),
+    }
+}
+
+// Append to the log file, creating it and its directory as needed
+fn open_log_file(path: &Path) -> Result<std::fs::File, ScreensaverError> {
+    let error = |e: std::io::Error| ScreensaverError::Logger(format!("{}: {}", path.display(), e));
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir).map_err(error)?;
+    }
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(error)
+}
+
+// Terminal logger that stays silent while a TerminalGuard owns the screen,
+// so log lines never land on top of a feature; the log file still gets them
+struct QuietWhileDrawing(Box<TermLogger>);
+
+impl log::Log for QuietWhileDrawing {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        self.0.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if !terminal_guard::is_active() {
+            self.0.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.0.flush();
+    }
+}
+
+impl SharedLogger for QuietWhileDrawing {
+    fn level(&self) -> LevelFilter {
+        self.0.level()
+    }
+
+    fn config(&self) -> Option<&Config> {
+        self.0.config()
+    }
+
+    fn as_log(self: Box<Self>) -> Box<dyn log::Log> {
+        self
+    }
 }
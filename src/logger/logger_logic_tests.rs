@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod logging_tests {
+    use crate::logger::logger_logic::*;
+    use simplelog::LevelFilter;
+    use std::path::PathBuf;
+
+    fn home() -> Option<String> {
+        Some("/home/user".to_string())
+    }
+
+    #[test]
+    fn test_log_file_defaults_to_xdg_state_dir() {
+        assert_eq!(
+            resolve_log_file(None, Some("/var/state".to_string()), home()),
+            Some(PathBuf::from(
+                "/var/state/terminal-screensaver/screensaver.log"
+            ))
+        );
+        assert_eq!(
+            resolve_log_file(None, None, home()),
+            Some(PathBuf::from(
+                "/home/user/.local/state/terminal-screensaver/screensaver.log"
+            ))
+        );
+        // Relative XDG paths are ignored
+        assert_eq!(
+            resolve_log_file(None, Some("state".to_string()), home()),
+            Some(PathBuf::from(
+                "/home/user/.local/state/terminal-screensaver/screensaver.log"
+            ))
+        );
+        assert_eq!(resolve_log_file(None, None, None), None);
+    }
+
+    #[test]
+    fn test_log_file_setting_overrides_default() {
+        assert_eq!(resolve_log_file(Some("none"), None, home()), None);
+        assert_eq!(
+            resolve_log_file(Some("~/logs/saver.log"), None, home()),
+            Some(PathBuf::from("/home/user/logs/saver.log"))
+        );
+        assert_eq!(
+            resolve_log_file(Some("saver.log"), Some("/var/state".to_string()), home()),
+            Some(PathBuf::from("saver.log"))
+        );
+    }
+
+    #[test]
+    fn test_terminal_only_gets_warnings_and_errors() {
+        assert_eq!(terminal_level(LevelFilter::Info), LevelFilter::Warn);
+        assert_eq!(terminal_level(LevelFilter::Trace), LevelFilter::Warn);
+        assert_eq!(terminal_level(LevelFilter::Error), LevelFilter::Error);
+        assert_eq!(terminal_level(LevelFilter::Off), LevelFilter::Off);
+    }
+}
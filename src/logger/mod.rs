@@ -1 +1,4 @@
 pub mod logger_logic;
+
+#[cfg(test)]
+pub mod logger_logic_tests;
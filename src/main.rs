@@ -1,12 +1,95 @@
-use terminal_screensaver::{cli, features};
+use std::time::Duration;
+use terminal_screensaver::{
+    cli,
+    shared::{
+        entropy_seed, terminal_guard, IdleWatcher, ScreensaverError, ScreensaverRuntime, SeededRng,
+    },
+    styles::theme_logic::theme_for,
+};
+
+// Print the error and exit non-zero; config diagnostics carry their own prefix
+fn exit_with(error: ScreensaverError) -> ! {
+    match &error {
+        ScreensaverError::Config(_) => eprintln!("{}", error),
+        _ => eprintln!("error: {}", error),
+    }
+    std::process::exit(1);
+}
 
 fn main() {
-    // Initialize logger
-    terminal_screensaver::logger::logger_logic::init();
+    // Never leave the shell in raw mode or on the alternate screen
+    terminal_guard::install_panic_hook();
 
     // Parse CLI arguments
-    let config = cli::cli_logic::parse_args();
+    let args = cli::cli_logic::parse_args();
+
+    if args.list_features {
+        cli::cli_logic::list_features();
+        return;
+    }
+
+    if args.check_config {
+        std::process::exit(cli::cli_logic::check_config(&args.config_path));
+    }
+
+    let (config, warnings) =
+        cli::cli_logic::read_config(&args.config_path).unwrap_or_else(|e| exit_with(e));
+
+    // Logging is configured by the file, so it starts once the file is read
+    let logging = cli::cli_logic::logging_config(&args, &config);
+    if let Err(e) = terminal_screensaver::logger::logger_logic::init(&logging) {
+        exit_with(e);
+    }
+    for warning in &warnings {
+        log::warn!("{}", warning);
+    }
+    if let Err(e) = terminal_guard::install_signal_handlers() {
+        log::warn!("Could not install signal handlers: {}", e);
+    }
+
+    if let Err(e) = cli::cli_logic::check_selection(args.feature.as_deref(), &config) {
+        exit_with(e);
+    }
+
+    let seed = args.seed.unwrap_or_else(entropy_seed);
+    log::info!(
+        "Random seed: {} (rerun with --seed {} to reproduce)",
+        seed,
+        seed
+    );
+    let mut rng = SeededRng::new(seed);
+    let mut start = |runtime: &ScreensaverRuntime| {
+        cli::cli_logic::start_screensaver(runtime, args.feature.as_deref(), &config, &mut rng)
+    };
+
+    let mut runtime = ScreensaverRuntime::new()
+        .with_config(&config.screensaver)
+        .with_background(theme_for(&config).background);
+    if let Some(fps) = args.fps {
+        runtime = runtime.with_fps(fps);
+    }
+
+    if args.watch {
+        let timeout = config.screensaver.timeout_seconds;
+        if timeout == 0 {
+            eprintln!("error: [screensaver] timeout_seconds is 0, which disables idle activation");
+            std::process::exit(1);
+        }
+
+        println!(
+            "Screensaver starts after {}s without input. Press Ctrl+C to stop watching.",
+            timeout
+        );
+        let watcher = IdleWatcher::new(Duration::from_secs(timeout)).with_runtime(runtime);
+        if let Err(e) = watcher.watch(start) {
+            log::error!("Idle watcher failed: {}", e);
+            exit_with(e);
+        }
+        return;
+    }
 
-    // Run the screen saver - using text_display feature by default
-    features::text_display::text_display_logic::run_screensaver(config);
+    if let Err(e) = start(&runtime) {
+        log::error!("Screensaver failed: {}", e);
+        exit_with(e);
+    }
 }
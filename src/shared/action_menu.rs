@@ -0,0 +1,225 @@
+use crate::cli::config_logic::ActionConfig;
+use crate::shared::text_width::{display_width, truncate_to_width};
+use crate::shared::{Rect, TextLine};
+use crate::styles::style_logic::Style;
+use crate::styles::theme_logic::Theme;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+const MENU_HINT: &str = "Up/Down/j/k move  Enter run  1-9 pick  Esc close";
+
+// What the caller should do after a key went to the menu
+#[derive(Debug, Clone, PartialEq)]
+pub enum MenuEvent {
+    Continue,
+    Close,
+    Run(ActionConfig),
+}
+
+// Navigable list of the configured actions, drawn as an overlay on top of a
+// feature's frame. Typing filters by key or description; j/k move the
+// selection until a filter has been started.
+pub struct ActionMenu {
+    actions: Vec<ActionConfig>,
+    filter: String,
+    selected: usize,
+    theme: Theme,
+}
+
+impl ActionMenu {
+    pub fn new(actions: Vec<ActionConfig>) -> Self {
+        Self {
+            actions,
+            filter: String::new(),
+            selected: 0,
+            theme: Theme::default(),
+        }
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    // Actions matching the filter, in config order
+    pub fn visible(&self) -> Vec<&ActionConfig> {
+        let filter = self.filter.to_lowercase();
+        self.actions
+            .iter()
+            .filter(|action| {
+                filter.is_empty()
+                    || action.key.to_lowercase() == filter
+                    || action.description.to_lowercase().contains(&filter)
+            })
+            .collect()
+    }
+
+    pub fn selected(&self) -> Option<&ActionConfig> {
+        self.visible().get(self.selected).copied()
+    }
+
+    pub fn handle_key(&mut self, key_event: KeyEvent) -> MenuEvent {
+        if key_event.modifiers.contains(KeyModifiers::CONTROL) {
+            return MenuEvent::Continue;
+        }
+
+        match key_event.code {
+            KeyCode::Esc if self.filter.is_empty() => return MenuEvent::Close,
+            KeyCode::Esc => self.set_filter(String::new()),
+            KeyCode::Enter => {
+                return match self.selected() {
+                    Some(action) => MenuEvent::Run(action.clone()),
+                    None => MenuEvent::Continue,
+                }
+            }
+            KeyCode::Up => self.move_selection(-1),
+            KeyCode::Down | KeyCode::Tab => self.move_selection(1),
+            KeyCode::Char('k') if self.filter.is_empty() => self.move_selection(-1),
+            KeyCode::Char('j') if self.filter.is_empty() => self.move_selection(1),
+            KeyCode::Char(c @ '1'..='9') => {
+                let index = c as usize - '1' as usize;
+                if let Some(action) = self.visible().get(index) {
+                    return MenuEvent::Run((*action).clone());
+                }
+            }
+            KeyCode::Backspace => {
+                let mut filter = self.filter.clone();
+                filter.pop();
+                self.set_filter(filter);
+            }
+            KeyCode::Char(c) => self.set_filter(format!("{}{}", self.filter, c)),
+            _ => {}
+        }
+        MenuEvent::Continue
+    }
+
+    // Draw the menu box centered over `lines`, which are padded to fill `area`
+    pub fn overlay(&self, lines: &mut Vec<TextLine>, area: &Rect) {
+        let box_lines = self.box_lines(area);
+        let box_width = box_lines
+            .first()
+            .map(|line| display_width(&line.content()))
+            .unwrap_or(0);
+        let top = (area.height as usize).saturating_sub(box_lines.len()) / 2;
+        let left = (area.width as usize).saturating_sub(box_width) / 2;
+
+        lines.resize(lines.len().max(area.height as usize), TextLine::default());
+        for (offset, box_line) in box_lines.iter().enumerate() {
+            if let Some(line) = lines.get_mut(top + offset) {
+                *line = line.overlay(left, box_line);
+            }
+        }
+    }
+
+    fn set_filter(&mut self, filter: String) {
+        self.filter = filter;
+        self.selected = 0;
+    }
+
+    fn move_selection(&mut self, step: isize) {
+        let count = self.visible().len();
+        if count > 0 {
+            self.selected = (self.selected as isize + step).rem_euclid(count as isize) as usize;
+        }
+    }
+
+    fn box_lines(&self, area: &Rect) -> Vec<TextLine> {
+        let border = self.theme.help_border.clone();
+        let text = self.theme.primary.clone();
+        let key_style = self.theme.help_text.clone().bold();
+        let selected_style = self.theme.help_text.clone().reverse();
+        let hint_style = self.theme.dim.clone();
+
+        let visible = self.visible();
+        let entries: Vec<(String, String)> = visible
+            .iter()
+            .enumerate()
+            .map(|(i, action)| {
+                let number = if i < 9 {
+                    format!("{}.", i + 1)
+                } else {
+                    "  ".to_string()
+                };
+                (
+                    format!("{} [{}]", number, action.key.to_uppercase()),
+                    action.description.clone(),
+                )
+            })
+            .collect();
+
+        // Inner width, without the two borders and their padding
+        let wanted = entries
+            .iter()
+            .map(|(key, description)| display_width(key) + 1 + display_width(description))
+            .chain([display_width(MENU_HINT), display_width(&self.filter) + 9])
+            .max()
+            .unwrap_or(0);
+        let inner = wanted.min((area.width as usize).saturating_sub(4)).max(1);
+
+        // Rows left for entries after borders, filter, separator and hint
+        let room = (area.height as usize).saturating_sub(6).max(1);
+        let first = (self.selected + 1).saturating_sub(room);
+
+        let row = |content: Vec<(String, Style)>| -> TextLine {
+            let mut line = TextLine::default();
+            line.push_styled("│ ", &border);
+            let mut used = 0;
+            for (part, style) in content {
+                let part = truncate_to_width(&part, inner - used);
+                used += display_width(part);
+                line.push_styled(part, &style);
+            }
+            line.push_styled(&" ".repeat(inner - used), &text);
+            line.push_styled(" │", &border);
+            line
+        };
+
+        let title = truncate_to_width(" Actions ", inner);
+        let mut lines = vec![TextLine::styled(
+            format!(
+                "┌─{}{}┐",
+                title,
+                "─".repeat((inner + 1).saturating_sub(display_width(title)))
+            ),
+            border.clone(),
+        )];
+        lines.push(row(vec![
+            ("Filter: ".to_string(), hint_style.clone()),
+            (format!("{}_", self.filter), text.clone()),
+        ]));
+
+        if entries.is_empty() {
+            lines.push(row(vec![(
+                "No matching actions".to_string(),
+                hint_style.clone(),
+            )]));
+        }
+        for (i, (key, description)) in entries.iter().enumerate().skip(first).take(room) {
+            if i == self.selected {
+                let label = format!("{} {}", key, description);
+                let padding = inner.saturating_sub(display_width(&label));
+                let padded = format!("{}{}", label, " ".repeat(padding));
+                lines.push(row(vec![(padded, selected_style.clone())]));
+            } else {
+                lines.push(row(vec![
+                    (key.clone(), key_style.clone()),
+                    (format!(" {}", description), text.clone()),
+                ]));
+            }
+        }
+
+        lines.push(TextLine::styled(
+            format!("├{}┤", "─".repeat(inner + 2)),
+            border.clone(),
+        ));
+        lines.push(row(vec![(MENU_HINT.to_string(), hint_style)]));
+        lines.push(TextLine::styled(
+            format!("└{}┘", "─".repeat(inner + 2)),
+            border,
+        ));
+        lines
+    }
+}
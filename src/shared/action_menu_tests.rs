@@ -0,0 +1,168 @@
+#[cfg(test)]
+mod menu_tests {
+    use crate::cli::config_logic::ActionConfig;
+    use crate::shared::action_menu::*;
+    use crate::shared::text_width::display_width;
+    use crate::shared::{Rect, TextLine};
+    use crossterm::event::{KeyCode, KeyEvent};
+    use crossterm::style::Attribute;
+
+    fn action(key: &str, description: &str) -> ActionConfig {
+        ActionConfig {
+            key: key.to_string(),
+            description: description.to_string(),
+            command: "true".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn menu() -> ActionMenu {
+        ActionMenu::new(vec![
+            action("d", "Deploy to staging"),
+            action("g", "Git status"),
+            action("l", "Tail logs"),
+        ])
+    }
+
+    fn press(menu: &mut ActionMenu, code: KeyCode) -> MenuEvent {
+        menu.handle_key(KeyEvent::from(code))
+    }
+
+    #[test]
+    fn test_arrows_and_jk_move_the_selection_and_wrap() {
+        let mut menu = menu();
+        assert_eq!(menu.selected().unwrap().key, "d");
+
+        press(&mut menu, KeyCode::Down);
+        press(&mut menu, KeyCode::Char('j'));
+        assert_eq!(menu.selected().unwrap().key, "l");
+
+        press(&mut menu, KeyCode::Down);
+        assert_eq!(menu.selected().unwrap().key, "d");
+
+        press(&mut menu, KeyCode::Char('k'));
+        press(&mut menu, KeyCode::Up);
+        assert_eq!(menu.selected().unwrap().key, "g");
+    }
+
+    #[test]
+    fn test_enter_runs_the_selected_action() {
+        let mut menu = menu();
+        press(&mut menu, KeyCode::Down);
+
+        assert_eq!(
+            press(&mut menu, KeyCode::Enter),
+            MenuEvent::Run(action("g", "Git status"))
+        );
+    }
+
+    #[test]
+    fn test_number_shortcuts_pick_from_the_visible_list() {
+        let mut menu = menu();
+        assert_eq!(
+            press(&mut menu, KeyCode::Char('3')),
+            MenuEvent::Run(action("l", "Tail logs"))
+        );
+        assert_eq!(press(&mut menu, KeyCode::Char('9')), MenuEvent::Continue);
+    }
+
+    #[test]
+    fn test_typing_filters_and_escape_clears_then_closes() {
+        let mut menu = menu();
+
+        for c in "sta".chars() {
+            press(&mut menu, KeyCode::Char(c));
+        }
+        let visible: Vec<&str> = menu.visible().iter().map(|a| a.key.as_str()).collect();
+        assert_eq!(visible, ["d", "g"]);
+
+        // Once filtering, j and k are text rather than movement
+        press(&mut menu, KeyCode::Char('g'));
+        press(&mut menu, KeyCode::Char('i'));
+        press(&mut menu, KeyCode::Char('n'));
+        assert_eq!(menu.filter(), "stagin");
+        assert_eq!(menu.visible().len(), 1);
+
+        press(&mut menu, KeyCode::Backspace);
+        assert_eq!(menu.filter(), "stagi");
+
+        assert_eq!(press(&mut menu, KeyCode::Esc), MenuEvent::Continue);
+        assert_eq!(menu.filter(), "");
+        assert_eq!(press(&mut menu, KeyCode::Esc), MenuEvent::Close);
+    }
+
+    #[test]
+    fn test_enter_with_no_matches_does_nothing() {
+        let mut menu = menu();
+        for c in "zzz".chars() {
+            press(&mut menu, KeyCode::Char(c));
+        }
+
+        assert!(menu.selected().is_none());
+        assert_eq!(press(&mut menu, KeyCode::Enter), MenuEvent::Continue);
+    }
+
+    #[test]
+    fn test_overlay_draws_a_centered_box_over_the_frame() {
+        let menu = menu();
+        let area = Rect::new(0, 0, 70, 12);
+        let mut lines: Vec<TextLine> = (0..12).map(|_| TextLine::new("#".repeat(70))).collect();
+
+        menu.overlay(&mut lines, &area);
+
+        let text: Vec<String> = lines.iter().map(|line| line.content()).collect();
+        let top = text.iter().position(|row| row.contains("Actions")).unwrap();
+        assert!(text[top].contains("┌─ Actions "));
+        assert!(text[top + 2].contains("1. [D] Deploy to staging"));
+        assert!(text[top + 4].contains("3. [L] Tail logs"));
+        assert!(text.iter().all(|row| row.chars().count() == 70));
+        assert!(text[0].chars().all(|c| c == '#'));
+    }
+
+    #[test]
+    fn test_selection_bar_is_padded_in_columns() {
+        let menu = ActionMenu::new(vec![
+            // Combining accents take no column of their own
+            action("r", "Re\u{301}sume\u{301} build"),
+            action("g", "Git status"),
+        ]);
+        let area = Rect::new(0, 0, 70, 12);
+        let mut lines = Vec::new();
+
+        menu.overlay(&mut lines, &area);
+
+        let bottom = lines
+            .iter()
+            .map(|line| line.content())
+            .find(|row| row.contains('└'))
+            .unwrap();
+        let box_width = display_width(bottom.trim());
+        let bar = lines
+            .iter()
+            .flat_map(|line| &line.spans)
+            .find(|span| span.style.attributes.contains(&Attribute::Reverse))
+            .unwrap();
+        assert!(bar.content.contains("build"));
+        // Everything between "│ " and " │"
+        assert_eq!(display_width(&bar.content), box_width - 4);
+    }
+
+    #[test]
+    fn test_title_is_clamped_on_narrow_screens() {
+        let menu = menu();
+        let area = Rect::new(0, 0, 9, 12);
+        let mut lines = Vec::new();
+
+        menu.overlay(&mut lines, &area);
+
+        let rows: Vec<String> = lines
+            .iter()
+            .map(|line| line.content().trim().to_string())
+            .filter(|row| !row.is_empty())
+            .collect();
+        assert!(rows[0].starts_with('┌'));
+        assert!(rows
+            .iter()
+            .all(|row| display_width(row) == display_width(&rows[0])));
+    }
+}
@@ -0,0 +1,497 @@
+use crate::cli::config_logic::ActionConfig;
+use crate::shared::text_width::display_width;
+use crate::shared::ScreensaverError;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, Clear, ClearType};
+use std::collections::VecDeque;
+use std::io::{stdin, stdout, BufRead, BufReader, Read, Write};
+#[cfg(unix)]
+use std::os::unix::process::CommandExt;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// Most lines kept from a captured action; older ones are dropped
+const MAX_CAPTURED_LINES: usize = 10_000;
+
+// How long a stopped action gets to exit after SIGTERM before it is killed
+#[cfg(unix)]
+const STOP_GRACE: Duration = Duration::from_millis(500);
+
+// Process groups of the actions spawned so far, for stop_running_actions
+#[cfg(unix)]
+static RUNNING_GROUPS: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+// How a configured action ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionOutcome {
+    Cancelled,
+    Finished(ExitStatus),
+    TimedOut,
+}
+
+impl ActionOutcome {
+    pub fn is_success(&self) -> bool {
+        matches!(self, ActionOutcome::Finished(status) if status.success())
+    }
+}
+
+// The process for an action: `argv` runs directly, `command` through bash.
+// On unix it leads its own process group, so stopping it also stops anything
+// it started.
+pub fn build_command(action: &ActionConfig) -> Command {
+    let mut command = match action.argv.split_first() {
+        Some((program, args)) => {
+            let mut command = Command::new(program);
+            command.args(args);
+            command
+        }
+        None => {
+            let mut command = Command::new("bash");
+            command.arg("-c").arg(&action.command);
+            command
+        }
+    };
+    if let Some(cwd) = &action.cwd {
+        command.current_dir(expand_home(cwd));
+    }
+    command.envs(&action.env);
+    #[cfg(unix)]
+    command.process_group(0);
+    command
+}
+
+// What is about to run, for prompts and logs
+pub fn describe_command(action: &ActionConfig) -> String {
+    if action.argv.is_empty() {
+        action.command.clone()
+    } else {
+        action.argv.join(" ")
+    }
+}
+
+// Wait for `child`, killing it once `timeout` has passed
+pub fn wait_with_timeout(
+    child: &mut Child,
+    timeout: Option<Duration>,
+) -> std::io::Result<ActionOutcome> {
+    let Some(timeout) = timeout else {
+        return child.wait().map(ActionOutcome::Finished);
+    };
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(ActionOutcome::Finished(status));
+        }
+        if Instant::now() >= deadline {
+            stop_process_group(child)?;
+            return Ok(ActionOutcome::TimedOut);
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+}
+
+// Stop a child spawned by `build_command` and everything in its process
+// group: SIGTERM first, then SIGKILL for whatever outlives STOP_GRACE
+#[cfg(unix)]
+pub fn stop_process_group(child: &mut Child) -> std::io::Result<()> {
+    signal_group(child, libc::SIGTERM)?;
+    let deadline = Instant::now() + STOP_GRACE;
+    while !has_exited(child)? && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    signal_group(child, libc::SIGKILL)?;
+    child.wait()?;
+    Ok(())
+}
+
+#[cfg(unix)]
+fn signal_group(child: &Child, signal: libc::c_int) -> std::io::Result<()> {
+    // The unreaped group leader keeps its pid, so the group can't be reused yet
+    if unsafe { libc::kill(-(child.id() as libc::pid_t), signal) } == 0 {
+        return Ok(());
+    }
+    match std::io::Error::last_os_error() {
+        // Nothing left in the group
+        e if e.raw_os_error() == Some(libc::ESRCH) => Ok(()),
+        e => Err(e),
+    }
+}
+
+// Whether the child has exited, without reaping it
+#[cfg(unix)]
+fn has_exited(child: &Child) -> std::io::Result<bool> {
+    has_exited_pid(child.id())
+}
+
+// Fails with ECHILD once `pid` has been reaped or isn't our child at all
+#[cfg(unix)]
+fn has_exited_pid(pid: u32) -> std::io::Result<bool> {
+    let mut info: libc::siginfo_t = unsafe { std::mem::zeroed() };
+    let result = unsafe {
+        libc::waitid(
+            libc::P_PID,
+            pid as libc::id_t,
+            &mut info,
+            libc::WEXITED | libc::WNOHANG | libc::WNOWAIT,
+        )
+    };
+    if result == -1 {
+        return Err(std::io::Error::last_os_error());
+    }
+    // WNOHANG leaves the pid zeroed while the child is still running
+    Ok(unsafe { info.si_pid() } != 0)
+}
+
+// Spawn an action and remember its process group, so the signal handler can
+// stop it before exiting
+pub(crate) fn spawn_action(command: &mut Command) -> std::io::Result<Child> {
+    let child = command.spawn()?;
+    #[cfg(unix)]
+    if let Ok(mut groups) = RUNNING_GROUPS.lock() {
+        groups.retain(|&pid| is_unreaped(pid));
+        groups.push(child.id());
+    }
+    Ok(child)
+}
+
+// Process groups of spawned actions that haven't been reaped yet
+#[cfg(unix)]
+pub(crate) fn running_groups() -> Vec<u32> {
+    RUNNING_GROUPS
+        .lock()
+        .map(|groups| {
+            groups
+                .iter()
+                .copied()
+                .filter(|&pid| is_unreaped(pid))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// An unreaped child still holds its pid, so its group id can't have been
+// reused, even when it has exited and left processes behind in the group
+#[cfg(unix)]
+fn is_unreaped(pid: u32) -> bool {
+    has_exited_pid(pid).is_ok()
+}
+
+// Stop every action that is still running. For the signal handler, which
+// exits without dropping the actions that would otherwise stop them.
+#[cfg(unix)]
+pub fn stop_running_actions() {
+    stop_groups(&running_groups());
+}
+
+// SIGTERM the groups led by `pids`, then SIGKILL them after STOP_GRACE or
+// once every leader has exited
+#[cfg(unix)]
+pub(crate) fn stop_groups(pids: &[u32]) {
+    if pids.is_empty() {
+        return;
+    }
+    let signal_all = |signal| {
+        for &pid in pids {
+            unsafe { libc::kill(-(pid as libc::pid_t), signal) };
+        }
+    };
+    signal_all(libc::SIGTERM);
+    let deadline = Instant::now() + STOP_GRACE;
+    while Instant::now() < deadline
+        && pids
+            .iter()
+            .any(|&pid| matches!(has_exited_pid(pid), Ok(false)))
+    {
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    signal_all(libc::SIGKILL);
+}
+
+// Hand the terminal's foreground to the action's process group from inside
+// the child, so it can read the keyboard and gets Ctrl+C instead of us
+#[cfg(unix)]
+fn take_terminal_foreground(command: &mut Command) {
+    if unsafe { libc::isatty(libc::STDIN_FILENO) } == 0 {
+        return;
+    }
+    unsafe {
+        command.pre_exec(|| {
+            // A background group changing the foreground would otherwise be stopped
+            libc::signal(libc::SIGTTOU, libc::SIG_IGN);
+            libc::tcsetpgrp(libc::STDIN_FILENO, libc::getpgrp());
+            libc::signal(libc::SIGTTOU, libc::SIG_DFL);
+            Ok(())
+        });
+    }
+}
+
+// Take the terminal's foreground back once the action has ended
+#[cfg(unix)]
+fn reclaim_terminal_foreground() {
+    if unsafe { libc::isatty(libc::STDIN_FILENO) } == 0 {
+        return;
+    }
+    unsafe {
+        let previous = libc::signal(libc::SIGTTOU, libc::SIG_IGN);
+        libc::tcsetpgrp(libc::STDIN_FILENO, libc::getpgrp());
+        libc::signal(libc::SIGTTOU, previous);
+    }
+}
+
+// Without process groups only the child itself can be stopped
+#[cfg(not(unix))]
+pub fn stop_process_group(child: &mut Child) -> std::io::Result<()> {
+    child.kill()?;
+    child.wait()?;
+    Ok(())
+}
+
+// The child already shares the console with us
+#[cfg(not(unix))]
+fn take_terminal_foreground(_command: &mut Command) {}
+
+#[cfg(not(unix))]
+fn reclaim_terminal_foreground() {}
+
+// Anything starting with y or Y confirms; an empty answer declines
+pub(crate) fn is_confirmed(answer: &str) -> bool {
+    answer.trim_start().starts_with(['y', 'Y'])
+}
+
+// Hand the terminal to the action, then give it back in raw mode with the
+// cursor hidden. The caller repaints the screen afterwards.
+pub fn run_action(action: &ActionConfig) -> Result<ActionOutcome, ScreensaverError> {
+    disable_raw_mode()?;
+    let result = execute!(stdout(), Clear(ClearType::All), MoveTo(0, 0), Show)
+        .and_then(|_| run_in_terminal(action))
+        .map_err(|e| ScreensaverError::action(&action.description, e));
+
+    // Back to the screensaver's terminal state even when the action failed
+    let restored = execute!(stdout(), Hide).and(enable_raw_mode());
+    let outcome = result?;
+    restored?;
+    Ok(outcome)
+}
+
+fn run_in_terminal(action: &ActionConfig) -> std::io::Result<ActionOutcome> {
+    let description = describe_command(action);
+
+    if action.confirm {
+        print!("Run \"{}\" ({})? [y/N] ", action.description, description);
+        stdout().flush()?;
+        let mut answer = String::new();
+        stdin().lock().read_line(&mut answer)?;
+        if !is_confirmed(&answer) {
+            log::info!("Action cancelled: {}", action.description);
+            return Ok(ActionOutcome::Cancelled);
+        }
+    }
+
+    log::info!("Executing action: {}", description);
+    let mut command = build_command(action);
+    take_terminal_foreground(&mut command);
+    command
+        .stdin(Stdio::inherit())
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit());
+    let outcome = spawn_action(&mut command)
+        .and_then(|mut child| wait_with_timeout(&mut child, action.timeout()));
+    reclaim_terminal_foreground();
+
+    match &outcome {
+        Ok(ActionOutcome::Finished(status)) if status.success() => {
+            log::info!("Action finished successfully")
+        }
+        Ok(ActionOutcome::Finished(status)) => {
+            log::error!("Action failed with status: {}", status);
+            println!("\nCommand failed with {}", status);
+        }
+        Ok(ActionOutcome::TimedOut) => {
+            let seconds = action.timeout_seconds.unwrap_or(0);
+            log::error!("Action timed out after {}s", seconds);
+            println!("\nCommand timed out after {}s and was stopped", seconds);
+        }
+        Ok(ActionOutcome::Cancelled) => {}
+        Err(e) => {
+            log::error!("Failed to execute action: {}", e);
+            println!("Error executing action: {}", e);
+        }
+    }
+
+    if action.pause {
+        println!("\nPress Enter to return to screensaver...");
+        let _ = stdin().read(&mut [0u8; 1]);
+    }
+    outcome
+}
+
+// One line of captured output
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputLine {
+    pub text: String,
+    pub is_stderr: bool,
+}
+
+// An action running in the background with stdout and stderr collected line
+// by line. The process group is stopped if this is dropped before it finishes.
+pub struct CapturedAction {
+    description: String,
+    child: Child,
+    output: Arc<Mutex<VecDeque<OutputLine>>>,
+    deadline: Option<Instant>,
+    outcome: Option<ActionOutcome>,
+}
+
+impl CapturedAction {
+    pub fn spawn(action: &ActionConfig) -> Result<Self, ScreensaverError> {
+        log::info!(
+            "Executing action with captured output: {}",
+            describe_command(action)
+        );
+        let mut command = build_command(action);
+        command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        let mut child = spawn_action(&mut command)
+            .map_err(|e| ScreensaverError::action(&action.description, e))?;
+
+        let output = Arc::new(Mutex::new(VecDeque::new()));
+        if let Some(stdout) = child.stdout.take() {
+            collect_lines(stdout, false, Arc::clone(&output));
+        }
+        if let Some(stderr) = child.stderr.take() {
+            collect_lines(stderr, true, Arc::clone(&output));
+        }
+
+        Ok(Self {
+            description: action.description.clone(),
+            child,
+            output,
+            deadline: action.timeout().map(|timeout| Instant::now() + timeout),
+            outcome: None,
+        })
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.output.lock().map(|lines| lines.len()).unwrap_or(0)
+    }
+
+    // Up to `count` lines from `start`, copied out without cloning the rest
+    pub fn lines(&self, start: usize, count: usize) -> Vec<OutputLine> {
+        self.output
+            .lock()
+            .map(|lines| {
+                lines
+                    .range(start.min(lines.len())..)
+                    .take(count)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    // The outcome once the process has ended, enforcing the timeout
+    pub fn poll(&mut self) -> Result<Option<ActionOutcome>, ScreensaverError> {
+        self.check_exit()
+            .map_err(|e| ScreensaverError::action(&self.description, e))
+    }
+
+    fn check_exit(&mut self) -> std::io::Result<Option<ActionOutcome>> {
+        if self.outcome.is_none() {
+            if let Some(status) = self.child.try_wait()? {
+                self.outcome = Some(ActionOutcome::Finished(status));
+            } else if self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+            {
+                stop_process_group(&mut self.child)?;
+                self.outcome = Some(ActionOutcome::TimedOut);
+            }
+            if let Some(outcome) = &self.outcome {
+                log::info!("Captured action ended: {:?}", outcome);
+            }
+        }
+        Ok(self.outcome)
+    }
+}
+
+impl Drop for CapturedAction {
+    fn drop(&mut self) {
+        if self.outcome.is_none() && matches!(self.child.try_wait(), Ok(None)) {
+            let _ = stop_process_group(&mut self.child);
+        }
+    }
+}
+
+fn collect_lines(
+    reader: impl Read + Send + 'static,
+    is_stderr: bool,
+    output: Arc<Mutex<VecDeque<OutputLine>>>,
+) {
+    std::thread::spawn(move || {
+        let mut reader = BufReader::new(reader);
+        let mut buffer = Vec::new();
+        while matches!(reader.read_until(b'\n', &mut buffer), Ok(n) if n > 0) {
+            let text = sanitize_output(&String::from_utf8_lossy(&buffer));
+            buffer.clear();
+            let Ok(mut lines) = output.lock() else {
+                return;
+            };
+            if lines.len() >= MAX_CAPTURED_LINES {
+                lines.pop_front();
+            }
+            lines.push_back(OutputLine { text, is_stderr });
+        }
+    });
+}
+
+// Printable text only: escape sequences and control characters are dropped
+// and tabs expanded to 8-column stops, so captured output can't move the
+// cursor or restyle the pane
+pub(crate) fn sanitize_output(line: &str) -> String {
+    let mut text = String::new();
+    let mut chars = line.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\u{1b}' => match chars.next() {
+                // CSI sequences end with a byte in @..~
+                Some('[') => {
+                    for next in chars.by_ref() {
+                        if ('@'..='~').contains(&next) {
+                            break;
+                        }
+                    }
+                }
+                // OSC sequences (titles, hyperlinks) end with BEL or ESC \
+                Some(']') => {
+                    while let Some(next) = chars.next() {
+                        if next == '\u{7}'
+                            || (next == '\u{1b}' && chars.next_if_eq(&'\\').is_some())
+                        {
+                            break;
+                        }
+                    }
+                }
+                // Other escapes take one character
+                _ => {}
+            },
+            '\t' => text.push_str(&" ".repeat(8 - display_width(&text) % 8)),
+            ch if ch.is_control() => {}
+            ch => text.push(ch),
+        }
+    }
+    text
+}
+
+// `~/` at the start of a path means the home directory
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{}/{}", home, rest),
+        _ => path.to_string(),
+    }
+}
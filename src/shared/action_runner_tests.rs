@@ -0,0 +1,219 @@
+#[cfg(test)]
+mod runner_tests {
+    use crate::cli::config_logic::ActionConfig;
+    use crate::shared::action_runner::*;
+    use std::ffi::OsStr;
+    use std::time::{Duration, Instant};
+
+    fn action() -> ActionConfig {
+        ActionConfig {
+            key: "s".to_string(),
+            description: "Status".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_argv_runs_without_a_shell() {
+        let action = ActionConfig {
+            argv: vec!["git".to_string(), "status".to_string(), "$HOME".to_string()],
+            ..action()
+        };
+        let command = build_command(&action);
+
+        assert_eq!(command.get_program(), "git");
+        let args: Vec<&OsStr> = command.get_args().collect();
+        assert_eq!(args, ["status", "$HOME"]);
+        assert_eq!(describe_command(&action), "git status $HOME");
+    }
+
+    #[test]
+    fn test_command_string_runs_through_bash() {
+        let action = ActionConfig {
+            command: "echo hi | wc -c".to_string(),
+            ..action()
+        };
+        let command = build_command(&action);
+
+        assert_eq!(command.get_program(), "bash");
+        let args: Vec<&OsStr> = command.get_args().collect();
+        assert_eq!(args, ["-c", "echo hi | wc -c"]);
+    }
+
+    #[test]
+    fn test_cwd_and_env_are_applied() {
+        let mut action = ActionConfig {
+            argv: vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                "test \"$(pwd)\" = / && test \"$GREETING\" = hello".to_string(),
+            ],
+            cwd: Some("/".to_string()),
+            ..action()
+        };
+        action
+            .env
+            .insert("GREETING".to_string(), "hello".to_string());
+
+        let command = build_command(&action);
+        assert_eq!(command.get_current_dir(), Some(std::path::Path::new("/")));
+
+        let status = build_command(&action).status().unwrap();
+        assert!(status.success());
+    }
+
+    #[test]
+    fn test_timeout_stops_long_running_actions() {
+        let action = ActionConfig {
+            argv: vec!["sleep".to_string(), "5".to_string()],
+            ..action()
+        };
+        let started = Instant::now();
+        let mut child = build_command(&action).spawn().unwrap();
+
+        let outcome = wait_with_timeout(&mut child, Some(Duration::from_millis(100))).unwrap();
+        assert_eq!(outcome, ActionOutcome::TimedOut);
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert!(!outcome.is_success());
+    }
+
+    // Running (or stopped), as opposed to gone or a zombie awaiting its reaper
+    #[cfg(unix)]
+    fn is_running(pid: &str) -> bool {
+        let output = std::process::Command::new("ps")
+            .args(["-o", "stat=", "-p", pid])
+            .output()
+            .unwrap();
+        let state = String::from_utf8_lossy(&output.stdout);
+        !state.trim().is_empty() && !state.trim_start().starts_with('Z')
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_timeout_stops_processes_started_by_the_action() {
+        use std::io::{BufRead, BufReader};
+
+        let action = ActionConfig {
+            command: "sleep 31337 & echo $!; wait".to_string(),
+            ..action()
+        };
+        let mut child = build_command(&action)
+            .stdout(std::process::Stdio::piped())
+            .spawn()
+            .unwrap();
+        let mut grandchild = String::new();
+        BufReader::new(child.stdout.take().unwrap())
+            .read_line(&mut grandchild)
+            .unwrap();
+        let grandchild = grandchild.trim();
+        assert!(is_running(grandchild));
+
+        let outcome = wait_with_timeout(&mut child, Some(Duration::from_millis(100))).unwrap();
+        assert_eq!(outcome, ActionOutcome::TimedOut);
+        assert!(!is_running(grandchild));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_captured_action_timeout_stops_processes_it_started() {
+        let action = ActionConfig {
+            command: "sleep 31337 & echo $!; wait".to_string(),
+            timeout_seconds: Some(1),
+            ..action()
+        };
+        let mut process = CapturedAction::spawn(&action).unwrap();
+        let started = Instant::now();
+        let outcome = loop {
+            if let Some(outcome) = process.poll().unwrap() {
+                break outcome;
+            }
+            assert!(started.elapsed() < Duration::from_secs(5));
+            std::thread::sleep(Duration::from_millis(20));
+        };
+        assert_eq!(outcome, ActionOutcome::TimedOut);
+
+        let grandchild = process.lines(0, 1)[0].text.clone();
+        assert!(!is_running(&grandchild));
+        // The output pipes close once the whole group is gone
+        std::thread::sleep(Duration::from_millis(100));
+        assert_eq!(process.line_count(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_running_actions_can_be_stopped_before_exiting() {
+        use std::io::{BufRead, BufReader};
+
+        let action = ActionConfig {
+            command: "sleep 31337 & echo $!; wait".to_string(),
+            ..action()
+        };
+        let mut command = build_command(&action);
+        command.stdout(std::process::Stdio::piped());
+        let mut child = spawn_action(&mut command).unwrap();
+        assert!(running_groups().contains(&child.id()));
+
+        let mut grandchild = String::new();
+        BufReader::new(child.stdout.take().unwrap())
+            .read_line(&mut grandchild)
+            .unwrap();
+        let grandchild = grandchild.trim();
+
+        // What the signal handler does for every running action
+        stop_groups(&[child.id()]);
+        assert!(!is_running(grandchild));
+
+        child.wait().unwrap();
+        assert!(!running_groups().contains(&child.id()));
+    }
+
+    #[test]
+    fn test_finished_actions_report_their_status() {
+        let run = |program: &str| {
+            let action = ActionConfig {
+                argv: vec![program.to_string()],
+                ..action()
+            };
+            let mut child = build_command(&action).spawn().unwrap();
+            wait_with_timeout(&mut child, Some(Duration::from_secs(5))).unwrap()
+        };
+
+        assert!(run("true").is_success());
+        assert!(!run("false").is_success());
+    }
+
+    #[test]
+    fn test_confirmation_answers() {
+        assert!(is_confirmed("y\n"));
+        assert!(is_confirmed("Yes\n"));
+        assert!(!is_confirmed("\n"));
+        assert!(!is_confirmed("n\n"));
+        assert!(!is_confirmed("nope"));
+    }
+
+    #[test]
+    fn test_captured_output_is_sanitized() {
+        assert_eq!(sanitize_output("\u{1b}[31mred\u{1b}[0m\r\n"), "red");
+        assert_eq!(sanitize_output("\u{1b}7bell\u{7}"), "bell");
+    }
+
+    #[test]
+    fn test_tabs_expand_to_eight_column_stops() {
+        assert_eq!(sanitize_output("a\tb"), "a       b");
+        assert_eq!(sanitize_output("\tx"), "        x");
+        assert_eq!(sanitize_output("12345678\tx"), "12345678        x");
+        // Wide characters count as two columns
+        assert_eq!(sanitize_output("日本\tx"), "日本    x");
+    }
+
+    #[test]
+    fn test_osc_sequences_are_stripped_whole() {
+        // Window title ended by BEL
+        assert_eq!(sanitize_output("\u{1b}]0;my title\u{7}prompt"), "prompt");
+        // Hyperlink ended by ESC \
+        assert_eq!(
+            sanitize_output("\u{1b}]8;;https://example.com\u{1b}\\link\u{1b}]8;;\u{1b}\\ done"),
+            "link done"
+        );
+    }
+}
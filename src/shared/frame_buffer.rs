@@ -0,0 +1,155 @@
+use crate::shared::text_width::{grapheme_width, graphemes};
+use crate::shared::TextLine;
+use crate::styles::style_logic::Style;
+use crossterm::style::Color;
+
+// One terminal cell, holding one grapheme. A wide glyph is stored in its
+// first cell and the cell it spills into holds an empty symbol so the grid
+// stays aligned with the screen.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cell {
+    pub symbol: String,
+    pub style: Style,
+}
+
+impl Cell {
+    pub fn blank() -> Self {
+        Self {
+            symbol: " ".to_string(),
+            style: Style::new(),
+        }
+    }
+
+    pub fn is_continuation(&self) -> bool {
+        self.symbol.is_empty()
+    }
+}
+
+// A full frame laid out as a grid of cells, used to diff against the previous frame
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrameBuffer {
+    pub width: u16,
+    pub height: u16,
+    cells: Vec<Cell>,
+}
+
+impl FrameBuffer {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![Cell::blank(); width as usize * height as usize],
+        }
+    }
+
+    pub fn from_lines(lines: &[TextLine], width: u16, height: u16) -> Self {
+        let mut buffer = Self::new(width, height);
+        for (y, line) in lines.iter().take(height as usize).enumerate() {
+            buffer.set_line(y as u16, line);
+        }
+        buffer
+    }
+
+    pub fn cell(&self, x: u16, y: u16) -> Option<&Cell> {
+        if x < self.width && y < self.height {
+            self.cells.get(self.index(x, y))
+        } else {
+            None
+        }
+    }
+
+    pub fn set(&mut self, x: u16, y: u16, cell: Cell) {
+        if x < self.width && y < self.height {
+            let index = self.index(x, y);
+            self.cells[index] = cell;
+        }
+    }
+
+    // Plain text of a row, mainly for tests and debugging
+    pub fn row_text(&self, y: u16) -> String {
+        (0..self.width)
+            .filter_map(|x| self.cell(x, y))
+            .map(|cell| cell.symbol.as_str())
+            .collect()
+    }
+
+    // Cells that differ from `previous`, in screen order. Everything is
+    // returned when there is no previous frame or the size changed.
+    pub fn diff<'a>(&'a self, previous: Option<&FrameBuffer>) -> Vec<(u16, u16, &'a Cell)> {
+        let previous = previous.filter(|p| p.width == self.width && p.height == self.height);
+
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(|(i, cell)| previous.is_none_or(|p| p.cells[*i] != **cell))
+            .map(|(i, cell)| {
+                let x = (i % self.width as usize) as u16;
+                let y = (i / self.width as usize) as u16;
+                (x, y, cell)
+            })
+            .collect()
+    }
+
+    // Give every cell without a background of its own `color`, so blank
+    // areas and unstyled text sit on the same background
+    pub fn fill_background(&mut self, color: Color) {
+        for cell in &mut self.cells {
+            cell.style.bg_color.get_or_insert(color);
+        }
+    }
+
+    fn index(&self, x: u16, y: u16) -> usize {
+        y as usize * self.width as usize + x as usize
+    }
+
+    fn set_line(&mut self, y: u16, line: &TextLine) {
+        let mut x: u16 = 0;
+        let mut last: Option<usize> = None;
+
+        for span in &line.spans {
+            for grapheme in graphemes(&span.content) {
+                // Control characters take no cell
+                if grapheme.chars().all(char::is_control) {
+                    continue;
+                }
+                let width = grapheme_width(grapheme) as u16;
+
+                // Stray zero-width characters attach to the previous glyph
+                if width == 0 {
+                    if let Some(index) = last {
+                        self.cells[index].symbol.push_str(grapheme);
+                    }
+                    continue;
+                }
+
+                if x >= self.width {
+                    return;
+                }
+                let index = self.index(x, y);
+
+                // A wide glyph that doesn't fit in the last column becomes a blank
+                if x + width > self.width {
+                    self.cells[index] = Cell {
+                        symbol: " ".to_string(),
+                        style: span.style.clone(),
+                    };
+                    return;
+                }
+
+                self.cells[index] = Cell {
+                    symbol: grapheme.to_string(),
+                    style: span.style.clone(),
+                };
+                for offset in 1..width {
+                    let continuation = self.index(x + offset, y);
+                    self.cells[continuation] = Cell {
+                        symbol: String::new(),
+                        style: span.style.clone(),
+                    };
+                }
+                last = Some(index);
+                x += width;
+            }
+        }
+    }
+}
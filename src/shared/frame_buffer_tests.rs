@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod frame_tests {
+    use crate::shared::frame_buffer::*;
+    use crate::shared::render_backend::queue_cells;
+    use crate::shared::{TextLine, TextSpan};
+    use crate::styles::color_logic::ColorSupport;
+    use crate::styles::style_logic::Style;
+    use crossterm::style::Color;
+
+    fn lines(rows: &[&str]) -> Vec<TextLine> {
+        rows.iter()
+            .map(|row| TextLine::new(row.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_from_lines_pads_and_truncates() {
+        let buffer = FrameBuffer::from_lines(&lines(&["abc", "toolongline", "x", "hidden"]), 5, 3);
+
+        assert_eq!(buffer.row_text(0), "abc  ");
+        assert_eq!(buffer.row_text(1), "toolo");
+        assert_eq!(buffer.row_text(2), "x    ");
+        assert_eq!(buffer.cell(5, 0), None);
+    }
+
+    #[test]
+    fn test_spans_keep_their_style_per_cell() {
+        let red = Style::new().fg(Color::Red);
+        let line =
+            TextLine::from_spans(vec![TextSpan::new("a"), TextSpan::styled("b", red.clone())]);
+        let buffer = FrameBuffer::from_lines(&[line], 4, 1);
+
+        assert_eq!(buffer.cell(0, 0).unwrap().style, Style::new());
+        assert_eq!(buffer.cell(1, 0).unwrap().style, red);
+    }
+
+    #[test]
+    fn test_wide_glyphs_take_two_cells() {
+        let buffer = FrameBuffer::from_lines(&lines(&["アb", "abcdア"]), 5, 2);
+
+        assert_eq!(buffer.cell(0, 0).unwrap().symbol, "ア");
+        assert!(buffer.cell(1, 0).unwrap().is_continuation());
+        assert_eq!(buffer.cell(2, 0).unwrap().symbol, "b");
+        // Only one column left, so the glyph is dropped rather than split
+        assert_eq!(buffer.row_text(1), "abcd ");
+    }
+
+    #[test]
+    fn test_combining_marks_join_the_previous_cell() {
+        let buffer = FrameBuffer::from_lines(&lines(&["e\u{301}x"]), 4, 1);
+
+        assert_eq!(buffer.cell(0, 0).unwrap().symbol, "e\u{301}");
+        assert_eq!(buffer.cell(1, 0).unwrap().symbol, "x");
+    }
+
+    #[test]
+    fn test_joined_emoji_take_one_wide_cell() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let buffer = FrameBuffer::from_lines(&lines(&[&format!("{}x", family)]), 4, 1);
+
+        assert_eq!(buffer.cell(0, 0).unwrap().symbol, family);
+        assert!(buffer.cell(1, 0).unwrap().is_continuation());
+        assert_eq!(buffer.cell(2, 0).unwrap().symbol, "x");
+    }
+
+    #[test]
+    fn test_diff_only_reports_changed_cells() {
+        let before = FrameBuffer::from_lines(&lines(&["hello", "world"]), 5, 2);
+        let after = FrameBuffer::from_lines(&lines(&["hallo", "world"]), 5, 2);
+
+        let changes = after.diff(Some(&before));
+        assert_eq!(changes.len(), 1);
+        assert_eq!((changes[0].0, changes[0].1), (1, 0));
+        assert_eq!(changes[0].2.symbol, "a");
+
+        assert!(after.diff(Some(&after.clone())).is_empty());
+    }
+
+    #[test]
+    fn test_diff_repaints_everything_without_matching_previous_frame() {
+        let small = FrameBuffer::new(3, 1);
+        let frame = FrameBuffer::new(4, 2);
+
+        assert_eq!(frame.diff(None).len(), 8);
+        assert_eq!(frame.diff(Some(&small)).len(), 8);
+    }
+
+    #[test]
+    fn test_queue_cells_skips_cursor_moves_for_adjacent_cells() {
+        let before = FrameBuffer::from_lines(&lines(&["....."]), 5, 1);
+        let after = FrameBuffer::from_lines(&lines(&[".ab.c"]), 5, 1);
+        let mut out = Vec::new();
+
+        queue_cells(
+            &mut out,
+            &after.diff(Some(&before)),
+            ColorSupport::TrueColor,
+        )
+        .unwrap();
+
+        let written = String::from_utf8(out).unwrap();
+        assert_eq!(written, "\u{1b}[1;2Hab\u{1b}[1;5Hc");
+    }
+
+    #[test]
+    fn test_background_fills_cells_without_their_own() {
+        let line = TextLine::from_spans(vec![
+            TextSpan::new("a"),
+            TextSpan::styled("b", Style::new().fg(Color::Red).bg(Color::Blue)),
+        ]);
+        let mut frame = FrameBuffer::from_lines(&[line], 3, 2);
+
+        frame.fill_background(Color::DarkBlue);
+
+        let background = |x, y| frame.cell(x, y).unwrap().style.bg_color;
+        assert_eq!(background(0, 0), Some(Color::DarkBlue));
+        assert_eq!(background(1, 0), Some(Color::Blue));
+        assert_eq!(background(2, 1), Some(Color::DarkBlue));
+        assert_eq!(frame.cell(1, 0).unwrap().style.color, Some(Color::Red));
+    }
+
+    #[test]
+    fn test_queue_cells_writes_backgrounds_and_attributes() {
+        let style = Style::new()
+            .fg(Color::Yellow)
+            .bg(Color::DarkBlue)
+            .bold()
+            .reverse();
+        let frame = FrameBuffer::from_lines(&[TextLine::styled("x".to_string(), style)], 1, 1);
+        let mut out = Vec::new();
+
+        queue_cells(&mut out, &frame.diff(None), ColorSupport::TrueColor).unwrap();
+
+        let written = String::from_utf8(out).unwrap();
+        assert_eq!(
+            written,
+            "\u{1b}[1;1H\u{1b}[0m\u{1b}[38;5;11m\u{1b}[48;5;4m\u{1b}[1m\u{1b}[7mx\u{1b}[0m"
+        );
+    }
+}
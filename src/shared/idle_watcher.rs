@@ -0,0 +1,101 @@
+use crate::shared::{ScreensaverError, ScreensaverRuntime, TerminalGuard};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::time::{Duration, Instant};
+
+// Tracks how long it has been since the last input
+pub struct IdleTimer {
+    timeout: Duration,
+    last_activity: Instant,
+}
+
+impl IdleTimer {
+    pub fn new(timeout: Duration, now: Instant) -> Self {
+        Self {
+            timeout,
+            last_activity: now,
+        }
+    }
+
+    pub fn record_activity(&mut self, now: Instant) {
+        self.last_activity = now;
+    }
+
+    pub fn remaining(&self, now: Instant) -> Duration {
+        self.timeout
+            .saturating_sub(now.saturating_duration_since(self.last_activity))
+    }
+
+    pub fn is_idle(&self, now: Instant) -> bool {
+        self.remaining(now).is_zero()
+    }
+}
+
+// Sits in the terminal waiting for input inactivity, then runs a feature
+// until the next keypress and goes back to waiting.
+pub struct IdleWatcher {
+    timeout: Duration,
+    runtime: ScreensaverRuntime,
+}
+
+impl IdleWatcher {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            runtime: ScreensaverRuntime::new().with_dismiss_on_any_key(true),
+        }
+    }
+
+    // Activate with `runtime`'s settings; any key still dismisses
+    pub fn with_runtime(mut self, runtime: ScreensaverRuntime) -> Self {
+        self.runtime = runtime.with_dismiss_on_any_key(true);
+        self
+    }
+
+    // `activate` runs the screensaver on every activation, so each run starts fresh
+    pub fn watch<F>(&self, mut activate: F) -> Result<(), ScreensaverError>
+    where
+        F: FnMut(&ScreensaverRuntime) -> Result<(), ScreensaverError>,
+    {
+        let guard = TerminalGuard::raw()?;
+        let result = self.watch_loop(&mut activate);
+        result.and(guard.restore().map_err(ScreensaverError::from))
+    }
+
+    fn watch_loop(
+        &self,
+        activate: &mut dyn FnMut(&ScreensaverRuntime) -> Result<(), ScreensaverError>,
+    ) -> Result<(), ScreensaverError> {
+        let mut timer = IdleTimer::new(self.timeout, Instant::now());
+
+        loop {
+            let remaining = timer.remaining(Instant::now());
+            if remaining.is_zero() {
+                log::info!("Idle for {}s, starting screensaver", self.timeout.as_secs());
+                activate(&self.runtime)?;
+                log::info!("Screensaver dismissed");
+                timer.record_activity(Instant::now());
+                continue;
+            }
+
+            if !event::poll(remaining)? {
+                continue;
+            }
+            match event::read()? {
+                Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                    if is_stop_key(&key_event) {
+                        return Ok(());
+                    }
+                    timer.record_activity(Instant::now());
+                }
+                Event::Mouse(_) | Event::Paste(_) => timer.record_activity(Instant::now()),
+                _ => {}
+            }
+        }
+    }
+}
+
+// Ctrl+C and Ctrl+D stop watching; raw mode keeps them from reaching us as signals
+pub(crate) fn is_stop_key(key_event: &KeyEvent) -> bool {
+    key_event.modifiers.contains(KeyModifiers::CONTROL)
+        && matches!(key_event.code, KeyCode::Char('c') | KeyCode::Char('d'))
+}
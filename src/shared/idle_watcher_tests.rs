@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod idle_tests {
+    use crate::shared::idle_watcher::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_timer_counts_down_from_last_activity() {
+        let start = Instant::now();
+        let timer = IdleTimer::new(Duration::from_secs(60), start);
+
+        assert_eq!(timer.remaining(start), Duration::from_secs(60));
+        assert_eq!(
+            timer.remaining(start + Duration::from_secs(45)),
+            Duration::from_secs(15)
+        );
+        assert!(!timer.is_idle(start + Duration::from_secs(59)));
+        assert!(timer.is_idle(start + Duration::from_secs(60)));
+        assert!(timer.is_idle(start + Duration::from_secs(600)));
+    }
+
+    #[test]
+    fn test_activity_resets_the_timer() {
+        let start = Instant::now();
+        let mut timer = IdleTimer::new(Duration::from_secs(10), start);
+
+        timer.record_activity(start + Duration::from_secs(8));
+
+        assert!(!timer.is_idle(start + Duration::from_secs(12)));
+        assert_eq!(
+            timer.remaining(start + Duration::from_secs(12)),
+            Duration::from_secs(6)
+        );
+    }
+
+    #[test]
+    fn test_stop_keys() {
+        assert!(is_stop_key(&KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL
+        )));
+        assert!(is_stop_key(&KeyEvent::new(
+            KeyCode::Char('d'),
+            KeyModifiers::CONTROL
+        )));
+        assert!(!is_stop_key(&KeyEvent::from(KeyCode::Char('c'))));
+        assert!(!is_stop_key(&KeyEvent::from(KeyCode::Esc)));
+    }
+}
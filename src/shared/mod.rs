@@ -1,5 +1,57 @@
+pub mod action_menu;
+pub mod action_runner;
+pub mod frame_buffer;
+pub mod idle_watcher;
+pub mod output_pane;
+pub mod playlist_rotator;
+pub mod render_backend;
+pub mod screensaver_error;
+pub mod screensaver_runtime;
+pub mod seeded_rng;
 pub mod shared_logic;
 pub mod simple_renderer;
+pub mod snapshot;
+pub mod terminal_guard;
+pub mod text_width;
+
+#[cfg(test)]
+pub mod action_menu_tests;
+#[cfg(test)]
+pub mod action_runner_tests;
+#[cfg(test)]
+pub mod frame_buffer_tests;
+#[cfg(test)]
+pub mod idle_watcher_tests;
+#[cfg(test)]
+pub mod output_pane_tests;
+#[cfg(test)]
+pub mod playlist_rotator_tests;
+#[cfg(test)]
+pub mod render_backend_tests;
+#[cfg(test)]
+pub mod screensaver_error_tests;
+#[cfg(test)]
+pub mod seeded_rng_tests;
+#[cfg(test)]
+pub mod shared_logic_tests;
+#[cfg(test)]
+pub mod simple_renderer_tests;
+#[cfg(test)]
+pub mod terminal_guard_tests;
+#[cfg(test)]
+pub mod text_width_tests;
 
 // Re-export commonly used items
-pub use simple_renderer::{Rect, SimpleRenderer, TextLine};
+pub use action_menu::{ActionMenu, MenuEvent};
+pub use action_runner::{run_action, ActionOutcome, CapturedAction, OutputLine};
+pub use frame_buffer::{Cell, FrameBuffer};
+pub use idle_watcher::{IdleTimer, IdleWatcher};
+pub use output_pane::{OutputPane, PaneStatus};
+pub use playlist_rotator::PlaylistRotator;
+pub use render_backend::{CrosstermBackend, MemoryBackend, RenderBackend};
+pub use screensaver_error::ScreensaverError;
+pub use screensaver_runtime::ScreensaverRuntime;
+pub use seeded_rng::{entropy_seed, SeededRng};
+pub use simple_renderer::{Rect, SimpleRenderer, TextLine, TextSpan};
+pub use terminal_guard::TerminalGuard;
+pub use text_width::{center_text, display_width, truncate_to_width};
@@ -0,0 +1,247 @@
+use crate::cli::config_logic::ActionConfig;
+use crate::shared::action_runner::{ActionOutcome, CapturedAction, OutputLine};
+use crate::shared::text_width::{display_width, truncate_to_width};
+use crate::shared::{Rect, TextLine};
+use crate::styles::style_logic::Style;
+use crate::styles::theme_logic::Theme;
+use crossterm::event::{KeyCode, KeyEvent};
+
+const PANE_HINT: &str = " j/k scroll  PgUp/PgDn page  Esc close ";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaneStatus {
+    Confirming,
+    Running,
+    Done(ActionOutcome),
+    Failed(String),
+}
+
+// Scrollable pane showing the captured output of an `output = "pane"` action,
+// drawn over the running feature. The border takes the theme's warning,
+// success or error role from the exit status.
+pub struct OutputPane {
+    action: ActionConfig,
+    process: Option<CapturedAction>,
+    status: PaneStatus,
+    scroll: usize,
+    // Keep the newest output in view until the user scrolls up
+    follow: bool,
+    page: usize,
+    theme: Theme,
+}
+
+impl OutputPane {
+    // Start `action` straight away, or ask first when it needs confirming
+    pub fn open(action: ActionConfig) -> Self {
+        let mut pane = Self {
+            status: PaneStatus::Confirming,
+            action,
+            process: None,
+            scroll: 0,
+            follow: true,
+            page: 1,
+            theme: Theme::default(),
+        };
+        if !pane.action.confirm {
+            pane.start();
+        }
+        pane
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub fn status(&self) -> &PaneStatus {
+        &self.status
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.process.as_ref().map_or(0, CapturedAction::line_count)
+    }
+
+    // Up to `count` captured lines from `start`
+    pub fn lines(&self, start: usize, count: usize) -> Vec<OutputLine> {
+        match &self.process {
+            Some(process) => process.lines(start, count),
+            None => Vec::new(),
+        }
+    }
+
+    // Pick up the exit status once the process ends
+    pub fn refresh(&mut self) {
+        if self.status != PaneStatus::Running {
+            return;
+        }
+        if let Some(process) = self.process.as_mut() {
+            match process.poll() {
+                Ok(Some(outcome)) => self.status = PaneStatus::Done(outcome),
+                Ok(None) => {}
+                Err(e) => self.status = PaneStatus::Failed(e.to_string()),
+            }
+        }
+    }
+
+    // Returns true when the pane should be closed; a running process is
+    // stopped when the pane is dropped
+    pub fn handle_key(&mut self, key_event: KeyEvent) -> bool {
+        if self.status == PaneStatus::Confirming {
+            if matches!(key_event.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                self.start();
+                return false;
+            }
+            log::info!("Action cancelled: {}", self.action.description);
+            return true;
+        }
+
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => return true,
+            KeyCode::Up | KeyCode::Char('k') => self.scroll_to(self.scroll.saturating_sub(1)),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll_to(self.scroll + 1),
+            KeyCode::PageUp => self.scroll_to(self.scroll.saturating_sub(self.page)),
+            KeyCode::PageDown => self.scroll_to(self.scroll + self.page),
+            KeyCode::Home | KeyCode::Char('g') => self.scroll_to(0),
+            KeyCode::End | KeyCode::Char('G') => self.scroll_to(self.line_count()),
+            _ => {}
+        }
+        false
+    }
+
+    // Draw the pane over `lines`, which are padded to fill `area`
+    pub fn overlay(&mut self, lines: &mut Vec<TextLine>, area: &Rect) {
+        if area.width < 8 || area.height < 4 {
+            return;
+        }
+        let left = if area.width >= 24 { 2 } else { 0 };
+        let top = if area.height >= 12 { 1 } else { 0 };
+        let inner = area.width as usize - left * 2 - 4;
+        self.page = (area.height as usize - top * 2 - 2).max(1);
+
+        // Only the visible rows are copied out of the capture buffer
+        let total = self.line_count();
+        let max_scroll = total.saturating_sub(self.page);
+        if self.follow || self.scroll > max_scroll {
+            self.scroll = max_scroll;
+        }
+        let output = self.lines(self.scroll, self.page);
+
+        let border = self.status_style().clone();
+        let mut pane_lines = vec![self.top_border(inner, &border)];
+        for row in 0..self.page {
+            let mut line = TextLine::default();
+            line.push_styled("│ ", &border);
+            let (text, style) = match output.get(row) {
+                Some(line) if line.is_stderr => (line.text.as_str(), self.theme.error.clone()),
+                Some(line) => (line.text.as_str(), self.theme.primary.clone()),
+                None if row == 0 => (self.placeholder(), self.theme.dim.clone()),
+                None => ("", Style::new()),
+            };
+            let text = truncate_to_width(text, inner);
+            let used = display_width(text);
+            line.push_styled(text, &style);
+            line.push_styled(&" ".repeat(inner - used), &Style::new());
+            line.push_styled(" │", &border);
+            pane_lines.push(line);
+        }
+        pane_lines.push(self.bottom_border(inner, &border, total));
+
+        lines.resize(lines.len().max(area.height as usize), TextLine::default());
+        for (offset, pane_line) in pane_lines.iter().enumerate() {
+            if let Some(line) = lines.get_mut(top + offset) {
+                *line = line.overlay(left, pane_line);
+            }
+        }
+    }
+
+    fn start(&mut self) {
+        match CapturedAction::spawn(&self.action) {
+            Ok(process) => {
+                self.process = Some(process);
+                self.status = PaneStatus::Running;
+            }
+            Err(e) => {
+                log::error!("Failed to execute action: {}", e);
+                self.status = PaneStatus::Failed(e.to_string());
+            }
+        }
+    }
+
+    fn scroll_to(&mut self, scroll: usize) {
+        let max_scroll = self.line_count().saturating_sub(self.page);
+        self.scroll = scroll.min(max_scroll);
+        self.follow = self.scroll == max_scroll;
+    }
+
+    fn status_style(&self) -> &Style {
+        match &self.status {
+            PaneStatus::Confirming | PaneStatus::Running => &self.theme.warning,
+            PaneStatus::Done(outcome) if outcome.is_success() => &self.theme.success,
+            PaneStatus::Done(_) | PaneStatus::Failed(_) => &self.theme.error,
+        }
+    }
+
+    fn status_text(&self) -> String {
+        match &self.status {
+            PaneStatus::Confirming => "confirm".to_string(),
+            PaneStatus::Running => "running".to_string(),
+            PaneStatus::Done(ActionOutcome::Finished(status)) => match status.code() {
+                Some(code) => format!("exit {}", code),
+                None => "killed".to_string(),
+            },
+            PaneStatus::Done(ActionOutcome::TimedOut) => "timed out".to_string(),
+            PaneStatus::Done(ActionOutcome::Cancelled) => "cancelled".to_string(),
+            PaneStatus::Failed(_) => "failed".to_string(),
+        }
+    }
+
+    fn placeholder(&self) -> &str {
+        match &self.status {
+            PaneStatus::Confirming => "Run this action? Press y to run, any other key to cancel",
+            PaneStatus::Running => "Waiting for output...",
+            PaneStatus::Failed(message) => message,
+            PaneStatus::Done(_) => "(no output)",
+        }
+    }
+
+    // ┌─ description ───── exit 0 ─┐
+    fn top_border(&self, inner: usize, border: &Style) -> TextLine {
+        let status = format!(" {} ", self.status_text());
+        let title_room = (inner + 2).saturating_sub(display_width(&status) + 3);
+        let title = format!(" {} ", self.action.description);
+        let title = truncate_to_width(&title, title_room);
+        let fill = (inner + 2).saturating_sub(display_width(title) + display_width(&status) + 2);
+
+        let mut line = TextLine::default();
+        line.push_styled("┌─", border);
+        line.push_styled(title, &self.theme.accent.clone().bold());
+        line.push_styled(&"─".repeat(fill), border);
+        line.push_styled(&status, &border.clone().bold());
+        line.push_styled("─┐", border);
+        line
+    }
+
+    // └─ j/k scroll ... ──── 12-30/40 ─┘
+    fn bottom_border(&self, inner: usize, border: &Style, total: usize) -> TextLine {
+        let position = if total > self.page {
+            format!(
+                " {}-{}/{} ",
+                self.scroll + 1,
+                (self.scroll + self.page).min(total),
+                total
+            )
+        } else {
+            String::new()
+        };
+        let hint = truncate_to_width(PANE_HINT, (inner + 2).saturating_sub(position.len() + 2));
+        let fill = (inner + 2).saturating_sub(display_width(hint) + position.len() + 2);
+
+        let mut line = TextLine::default();
+        line.push_styled("└─", border);
+        line.push_styled(hint, &self.theme.dim);
+        line.push_styled(&"─".repeat(fill), border);
+        line.push_styled(&position, border);
+        line.push_styled("─┘", border);
+        line
+    }
+}
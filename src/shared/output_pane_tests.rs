@@ -0,0 +1,181 @@
+#[cfg(test)]
+mod pane_tests {
+    use crate::cli::config_logic::{ActionConfig, ActionOutput};
+    use crate::shared::output_pane::*;
+    use crate::shared::{Rect, TextLine};
+    use crate::styles::style_logic::Style;
+    use crate::styles::theme_logic::Theme;
+    use crossterm::event::{KeyCode, KeyEvent};
+    use crossterm::style::Color;
+    use std::time::{Duration, Instant};
+
+    fn action(script: &str) -> ActionConfig {
+        ActionConfig {
+            key: "s".to_string(),
+            description: "Status".to_string(),
+            argv: vec!["sh".to_string(), "-c".to_string(), script.to_string()],
+            output: ActionOutput::Pane,
+            ..Default::default()
+        }
+    }
+
+    // Refresh until the process has ended and its output has been read
+    fn finish(pane: &mut OutputPane, lines: usize) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while (*pane.status() == PaneStatus::Running || pane.line_count() < lines)
+            && Instant::now() < deadline
+        {
+            pane.refresh();
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    fn border_color(line: &TextLine) -> Option<Color> {
+        line.spans
+            .iter()
+            .find(|span| span.content.contains('┌'))
+            .and_then(|span| span.style.color)
+    }
+
+    fn draw(pane: &mut OutputPane, area: &Rect) -> Vec<TextLine> {
+        let mut lines = Vec::new();
+        pane.overlay(&mut lines, area);
+        lines
+    }
+
+    #[test]
+    fn test_output_is_captured_with_stderr_marked() {
+        let mut pane = OutputPane::open(action("echo out; echo err >&2"));
+        finish(&mut pane, 2);
+
+        assert_eq!(pane.line_count(), 2);
+        let lines = pane.lines(0, 10);
+        assert_eq!(lines.len(), 2);
+        assert!(lines.iter().any(|l| l.text == "out" && !l.is_stderr));
+        assert!(lines.iter().any(|l| l.text == "err" && l.is_stderr));
+    }
+
+    #[test]
+    fn test_border_color_follows_exit_status() {
+        let area = Rect::new(0, 0, 40, 10);
+
+        let mut ok = OutputPane::open(action("echo fine"));
+        finish(&mut ok, 1);
+        // Short screens get no margin above the pane
+        let frame = draw(&mut ok, &area);
+        assert!(frame[0].content().contains(" exit 0 "));
+        assert_eq!(border_color(&frame[0]), Some(Color::Green));
+
+        let mut failed = OutputPane::open(action("exit 3"));
+        finish(&mut failed, 0);
+        let frame = draw(&mut failed, &area);
+        assert!(frame[0].content().contains(" exit 3 "));
+        assert_eq!(border_color(&frame[0]), Some(Color::Red));
+        assert!(frame[1].content().contains("(no output)"));
+    }
+
+    #[test]
+    fn test_status_and_stderr_colors_follow_the_theme() {
+        let area = Rect::new(0, 0, 40, 10);
+        let theme = Theme {
+            success: Style::new().fg(Color::Blue),
+            warning: Style::new().fg(Color::Grey),
+            error: Style::new().fg(Color::Magenta),
+            ..Theme::default()
+        };
+
+        let mut failed = OutputPane::open(action("echo err >&2; exit 1")).with_theme(theme.clone());
+        finish(&mut failed, 1);
+        let frame = draw(&mut failed, &area);
+        assert_eq!(border_color(&frame[0]), Some(Color::Magenta));
+        assert!(frame[1]
+            .spans
+            .iter()
+            .any(|span| span.content.ends_with("err") && span.style == theme.error));
+
+        let mut ok = OutputPane::open(action("true")).with_theme(theme.clone());
+        finish(&mut ok, 0);
+        assert_eq!(border_color(&draw(&mut ok, &area)[0]), Some(Color::Blue));
+
+        let mut running = OutputPane::open(action("sleep 5")).with_theme(theme);
+        assert_eq!(
+            border_color(&draw(&mut running, &area)[0]),
+            Some(Color::Grey)
+        );
+    }
+
+    #[test]
+    fn test_pane_fits_the_area_and_follows_the_tail() {
+        let area = Rect::new(0, 0, 40, 12);
+        let mut pane = OutputPane::open(action("seq 1 30"));
+        finish(&mut pane, 30);
+
+        let frame = draw(&mut pane, &area);
+        assert_eq!(frame.len(), 12);
+        assert!(frame
+            .iter()
+            .all(|line| line.content().chars().count() <= 40));
+        // Eight output rows fit between the borders; the newest ones are shown
+        assert!(frame[9].content().contains("│ 30 "));
+        assert!(frame[10].content().contains(" 23-30/30 "));
+
+        pane.handle_key(KeyEvent::from(KeyCode::Home));
+        let frame = draw(&mut pane, &area);
+        assert!(frame[2].content().contains("│ 1 "));
+
+        pane.handle_key(KeyEvent::from(KeyCode::PageDown));
+        let frame = draw(&mut pane, &area);
+        assert!(frame[2].content().contains("│ 9 "));
+    }
+
+    #[test]
+    fn test_oldest_lines_are_dropped_past_the_cap() {
+        let mut pane = OutputPane::open(action("seq 1 10005"));
+        finish(&mut pane, 10_000);
+
+        assert_eq!(pane.line_count(), 10_000);
+        assert_eq!(pane.lines(0, 1)[0].text, "6");
+        let tail = pane.lines(9_998, 5);
+        assert_eq!(tail.len(), 2);
+        assert_eq!(tail[1].text, "10005");
+    }
+
+    #[test]
+    fn test_confirm_waits_for_y() {
+        let confirmed = ActionConfig {
+            confirm: true,
+            ..action("echo yes")
+        };
+
+        let mut pane = OutputPane::open(confirmed.clone());
+        assert_eq!(*pane.status(), PaneStatus::Confirming);
+        assert!(!pane.handle_key(KeyEvent::from(KeyCode::Char('y'))));
+        finish(&mut pane, 1);
+        assert_eq!(pane.lines(0, 1)[0].text, "yes");
+
+        let mut declined = OutputPane::open(confirmed);
+        assert!(declined.handle_key(KeyEvent::from(KeyCode::Char('n'))));
+        assert_eq!(declined.line_count(), 0);
+    }
+
+    #[test]
+    fn test_timeout_is_shown_as_failure() {
+        let slow = ActionConfig {
+            timeout_seconds: Some(1),
+            ..action("sleep 5")
+        };
+        let started = Instant::now();
+        let mut pane = OutputPane::open(slow);
+        finish(&mut pane, 0);
+
+        assert!(started.elapsed() < Duration::from_secs(4));
+        let frame = draw(&mut pane, &Rect::new(0, 0, 40, 10));
+        assert!(frame[0].content().contains(" timed out "));
+    }
+
+    #[test]
+    fn test_escape_closes_the_pane() {
+        let mut pane = OutputPane::open(action("sleep 5"));
+        assert!(pane.handle_key(KeyEvent::from(KeyCode::Esc)));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::cli::config_logic::{Config, PlaylistOrder, PlaylistTransition};
+use crate::features::{create_feature, ScreensaverFeature};
+use crate::shared::{ScreensaverError, SeededRng, TextLine};
+use std::time::{Duration, Instant};
+
+// How long a wipe between two playlist entries takes
+pub const WIPE_DURATION: Duration = Duration::from_millis(600);
+
+// Steps through the enabled `[[playlist.entries]]`, in order or shuffled,
+// and says when the current entry has used up its duration.
+pub struct PlaylistRotator {
+    config: Config,
+    entries: Vec<(String, Duration)>,
+    order: Vec<usize>,
+    position: usize,
+    started: Instant,
+    rng: SeededRng,
+}
+
+impl PlaylistRotator {
+    pub fn new(config: &Config, now: Instant, rng: SeededRng) -> Result<Self, ScreensaverError> {
+        let playlist = &config.playlist;
+        let entries: Vec<(String, Duration)> = playlist
+            .entries
+            .iter()
+            .filter(|entry| {
+                let enabled = config.features.is_enabled(&entry.feature);
+                if !enabled {
+                    log::warn!("Skipping disabled playlist entry: {}", entry.feature);
+                }
+                enabled
+            })
+            .map(|entry| (entry.feature.clone(), playlist.duration_of(entry)))
+            .collect();
+
+        if entries.is_empty() {
+            return Err(ScreensaverError::Feature(
+                "the playlist has no enabled entries".to_string(),
+            ));
+        }
+
+        let mut rotator = Self {
+            config: config.clone(),
+            order: (0..entries.len()).collect(),
+            entries,
+            position: 0,
+            started: now,
+            rng,
+        };
+        if rotator.is_shuffled() {
+            rotator.shuffle(None);
+        }
+        Ok(rotator)
+    }
+
+    pub fn current_name(&self) -> &str {
+        &self.entries[self.order[self.position]].0
+    }
+
+    pub fn current_duration(&self) -> Duration {
+        self.entries[self.order[self.position]].1
+    }
+
+    pub fn transition(&self) -> PlaylistTransition {
+        self.config.playlist.transition
+    }
+
+    pub fn is_due(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.started) >= self.current_duration()
+    }
+
+    // Move to the next entry, reshuffling at the end of each pass when shuffled
+    pub fn advance(&mut self, now: Instant) -> &str {
+        self.position += 1;
+        if self.position >= self.order.len() {
+            self.position = 0;
+            if self.is_shuffled() {
+                let last = self.order.last().copied();
+                self.shuffle(last);
+            }
+        }
+        self.started = now;
+        self.current_name()
+    }
+
+    pub fn create_current(&mut self) -> Result<Box<dyn ScreensaverFeature>, ScreensaverError> {
+        let name = &self.entries[self.order[self.position]].0;
+        create_feature(name, &self.config, &mut self.rng)
+            .ok_or_else(|| ScreensaverError::Feature(format!("unknown feature '{}'", name)))
+    }
+
+    fn is_shuffled(&self) -> bool {
+        self.config.playlist.order == PlaylistOrder::Shuffle
+    }
+
+    // Fisher-Yates shuffle; `avoid_first` keeps the previous entry from playing twice in a row
+    fn shuffle(&mut self, avoid_first: Option<usize>) {
+        for i in (1..self.order.len()).rev() {
+            let j = self.rng.index(i + 1);
+            self.order.swap(i, j);
+        }
+        if self.order.len() > 1 && avoid_first == Some(self.order[0]) {
+            self.order.swap(0, 1);
+        }
+    }
+}
+
+// Top-to-bottom wipe: rows above the edge come from the incoming frame
+pub fn wipe_lines(
+    outgoing: Vec<TextLine>,
+    incoming: Vec<TextLine>,
+    progress: f64,
+    height: u16,
+) -> Vec<TextLine> {
+    let edge = (height as f64 * progress.clamp(0.0, 1.0)).round() as usize;
+    let mut outgoing = outgoing.into_iter();
+    let mut incoming = incoming.into_iter();
+
+    (0..height as usize)
+        .map(|row| {
+            let from_incoming = incoming.next();
+            let from_outgoing = outgoing.next();
+            let line = if row < edge {
+                from_incoming
+            } else {
+                from_outgoing
+            };
+            line.unwrap_or_default()
+        })
+        .collect()
+}
@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod rotator_tests {
+    use crate::cli::config_logic::{parse_config, Config};
+    use crate::shared::playlist_rotator::*;
+    use crate::shared::{SeededRng, TextLine};
+    use std::time::{Duration, Instant};
+
+    fn config(playlist: &str) -> Config {
+        parse_config(playlist).unwrap()
+    }
+
+    const LAB_PLAYLIST: &str = r#"
+[playlist]
+default_duration_seconds = 20
+
+[[playlist.entries]]
+feature = "clock_display"
+duration_seconds = 30
+
+[[playlist.entries]]
+feature = "system_info"
+
+[[playlist.entries]]
+feature = "matrix_rain"
+"#;
+
+    #[test]
+    fn test_sequential_rotation_wraps_around() {
+        let start = Instant::now();
+        let mut rotator =
+            PlaylistRotator::new(&config(LAB_PLAYLIST), start, SeededRng::new(7)).unwrap();
+
+        assert_eq!(rotator.current_name(), "clock_display");
+        assert_eq!(rotator.advance(start), "system_info");
+        assert_eq!(rotator.advance(start), "matrix_rain");
+        assert_eq!(rotator.advance(start), "clock_display");
+    }
+
+    #[test]
+    fn test_entries_become_due_after_their_duration() {
+        let start = Instant::now();
+        let mut rotator =
+            PlaylistRotator::new(&config(LAB_PLAYLIST), start, SeededRng::new(7)).unwrap();
+
+        assert_eq!(rotator.current_duration(), Duration::from_secs(30));
+        assert!(!rotator.is_due(start + Duration::from_secs(29)));
+        assert!(rotator.is_due(start + Duration::from_secs(30)));
+
+        // The timer restarts on advance and falls back to the default duration
+        let later = start + Duration::from_secs(30);
+        rotator.advance(later);
+        assert_eq!(rotator.current_duration(), Duration::from_secs(20));
+        assert!(!rotator.is_due(later + Duration::from_secs(19)));
+    }
+
+    #[test]
+    fn test_shuffle_plays_every_entry_each_pass() {
+        let shuffled = format!(
+            "{}\n",
+            LAB_PLAYLIST.replace("[playlist]\n", "[playlist]\norder = \"shuffle\"\n",)
+        );
+        let start = Instant::now();
+        let mut rotator =
+            PlaylistRotator::new(&config(&shuffled), start, SeededRng::new(7)).unwrap();
+
+        let mut previous = rotator.current_name().to_string();
+        for _ in 0..5 {
+            let mut pass = vec![previous.clone()];
+            for _ in 1..3 {
+                pass.push(rotator.advance(start).to_string());
+            }
+            pass.sort();
+            assert_eq!(pass, ["clock_display", "matrix_rain", "system_info"]);
+
+            let last = rotator.current_name().to_string();
+            previous = rotator.advance(start).to_string();
+            assert_ne!(previous, last, "entry repeated across passes");
+        }
+    }
+
+    #[test]
+    fn test_same_seed_gives_same_shuffle() {
+        let shuffled = LAB_PLAYLIST.replace("[playlist]\n", "[playlist]\norder = \"shuffle\"\n");
+        let start = Instant::now();
+        let play = |seed: u64| -> Vec<String> {
+            let mut rotator =
+                PlaylistRotator::new(&config(&shuffled), start, SeededRng::new(seed)).unwrap();
+            (0..9).map(|_| rotator.advance(start).to_string()).collect()
+        };
+
+        assert_eq!(play(42), play(42));
+    }
+
+    #[test]
+    fn test_disabled_entries_are_skipped() {
+        let content = format!(
+            "{}\n[features.system_info]\nenabled = false\n",
+            LAB_PLAYLIST
+        );
+        let start = Instant::now();
+        let mut rotator =
+            PlaylistRotator::new(&config(&content), start, SeededRng::new(7)).unwrap();
+
+        assert_eq!(rotator.advance(start), "matrix_rain");
+    }
+
+    #[test]
+    fn test_playlist_without_enabled_entries_is_an_error() {
+        let content = "[[playlist.entries]]\nfeature = \"starfield\"\n\n[features.starfield]\nenabled = false\n";
+
+        assert!(PlaylistRotator::new(&config(content), Instant::now(), SeededRng::new(7)).is_err());
+    }
+
+    #[test]
+    fn test_wipe_takes_rows_from_the_incoming_frame() {
+        let frame = |text: &str| -> Vec<TextLine> {
+            (0..4).map(|_| TextLine::new(text.to_string())).collect()
+        };
+
+        let halfway = wipe_lines(frame("old"), frame("new"), 0.5, 4);
+        let rows: Vec<String> = halfway.iter().map(TextLine::content).collect();
+        assert_eq!(rows, ["new", "new", "old", "old"]);
+
+        let done = wipe_lines(frame("old"), frame("new"), 1.0, 4);
+        assert!(done.iter().all(|line| line.content() == "new"));
+
+        // Short frames are padded with blank rows
+        assert_eq!(wipe_lines(Vec::new(), frame("new"), 0.0, 4).len(), 4);
+    }
+}
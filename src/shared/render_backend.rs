@@ -0,0 +1,180 @@
+use crate::shared::frame_buffer::{Cell, FrameBuffer};
+use crate::shared::text_width::display_width;
+use crate::styles::color_logic::{downsample, ColorSupport};
+use crate::styles::style_logic::Style;
+use crossterm::{
+    cursor,
+    style::{Attribute, Print, SetAttribute, SetBackgroundColor, SetForegroundColor},
+    terminal::{BeginSynchronizedUpdate, Clear, ClearType, EndSynchronizedUpdate},
+    QueueableCommand,
+};
+use std::io::{stdout, Stdout, Write};
+
+// Where SimpleRenderer sends finished frames
+pub trait RenderBackend {
+    fn size(&self) -> std::io::Result<(u16, u16)>;
+
+    fn clear(&mut self) -> std::io::Result<()>;
+
+    // `previous` is what the screen currently shows, or None when unknown
+    fn draw(&mut self, frame: &FrameBuffer, previous: Option<&FrameBuffer>) -> std::io::Result<()>;
+}
+
+// Writes frames to a terminal with crossterm, only touching changed cells.
+// Colors the terminal cannot show are replaced by the closest it can.
+pub struct CrosstermBackend<W: Write = Stdout> {
+    out: W,
+    colors: ColorSupport,
+}
+
+impl Default for CrosstermBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CrosstermBackend {
+    pub fn new() -> Self {
+        let colors = ColorSupport::detect();
+        log::debug!("Terminal color support: {:?}", colors);
+        Self::with_writer(stdout()).with_color_support(colors)
+    }
+}
+
+impl<W: Write> CrosstermBackend<W> {
+    pub fn with_writer(out: W) -> Self {
+        Self {
+            out,
+            colors: ColorSupport::TrueColor,
+        }
+    }
+
+    pub fn with_color_support(mut self, colors: ColorSupport) -> Self {
+        self.colors = colors;
+        self
+    }
+}
+
+impl<W: Write> RenderBackend for CrosstermBackend<W> {
+    fn size(&self) -> std::io::Result<(u16, u16)> {
+        crossterm::terminal::size()
+    }
+
+    fn clear(&mut self) -> std::io::Result<()> {
+        self.out
+            .queue(Clear(ClearType::All))?
+            .queue(cursor::MoveTo(0, 0))?;
+        self.out.flush()
+    }
+
+    fn draw(&mut self, frame: &FrameBuffer, previous: Option<&FrameBuffer>) -> std::io::Result<()> {
+        // Terminals without synchronized output ignore these sequences
+        self.out.queue(BeginSynchronizedUpdate)?;
+        if previous.is_none() {
+            self.out.queue(Clear(ClearType::All))?;
+        }
+        queue_cells(&mut self.out, &frame.diff(previous), self.colors)?;
+        self.out.queue(EndSynchronizedUpdate)?;
+        self.out.flush()
+    }
+}
+
+// Keeps the screen as a cell grid in memory, for tests and headless rendering
+pub struct MemoryBackend {
+    screen: FrameBuffer,
+    frames_drawn: usize,
+}
+
+impl MemoryBackend {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            screen: FrameBuffer::new(width, height),
+            frames_drawn: 0,
+        }
+    }
+
+    // Simulate a terminal resize; call SimpleRenderer::update_size afterwards
+    pub fn set_size(&mut self, width: u16, height: u16) {
+        self.screen = FrameBuffer::new(width, height);
+    }
+
+    pub fn screen(&self) -> &FrameBuffer {
+        &self.screen
+    }
+
+    pub fn frames_drawn(&self) -> usize {
+        self.frames_drawn
+    }
+}
+
+impl RenderBackend for MemoryBackend {
+    fn size(&self) -> std::io::Result<(u16, u16)> {
+        Ok((self.screen.width, self.screen.height))
+    }
+
+    fn clear(&mut self) -> std::io::Result<()> {
+        self.screen = FrameBuffer::new(self.screen.width, self.screen.height);
+        Ok(())
+    }
+
+    fn draw(&mut self, frame: &FrameBuffer, previous: Option<&FrameBuffer>) -> std::io::Result<()> {
+        if previous.is_none() {
+            self.clear()?;
+        }
+        // Apply only the diff, the same as a terminal would see it
+        for (x, y, cell) in frame.diff(previous) {
+            self.screen.set(x, y, cell.clone());
+        }
+        self.frames_drawn += 1;
+        Ok(())
+    }
+}
+
+// Write changed cells, only moving the cursor or switching style when needed
+pub(crate) fn queue_cells(
+    out: &mut impl Write,
+    changes: &[(u16, u16, &Cell)],
+    colors: ColorSupport,
+) -> std::io::Result<()> {
+    let mut cursor: Option<(u16, u16)> = None;
+    let mut current = Style::new();
+
+    for &(x, y, cell) in changes {
+        // The leading half of a wide glyph already covers this cell
+        if cell.is_continuation() {
+            continue;
+        }
+
+        if cursor != Some((x, y)) {
+            out.queue(cursor::MoveTo(x, y))?;
+        }
+        if cell.style != current {
+            apply_style(out, &cell.style, colors)?;
+            current = cell.style.clone();
+        }
+
+        out.queue(Print(&cell.symbol))?;
+        let width = display_width(&cell.symbol);
+        cursor = Some((x + width.max(1) as u16, y));
+    }
+
+    // Reset so the style doesn't bleed into anything printed after the frame
+    if current != Style::new() {
+        out.queue(SetAttribute(Attribute::Reset))?;
+    }
+    Ok(())
+}
+
+fn apply_style(out: &mut impl Write, style: &Style, colors: ColorSupport) -> std::io::Result<()> {
+    out.queue(SetAttribute(Attribute::Reset))?;
+    if let Some(color) = style.color.and_then(|c| downsample(c, colors)) {
+        out.queue(SetForegroundColor(color))?;
+    }
+    if let Some(color) = style.bg_color.and_then(|c| downsample(c, colors)) {
+        out.queue(SetBackgroundColor(color))?;
+    }
+    for attribute in &style.attributes {
+        out.queue(SetAttribute(*attribute))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod backend_tests {
+    use crate::shared::render_backend::*;
+    use crate::shared::snapshot::screen_text;
+    use crate::shared::{FrameBuffer, SimpleRenderer, TextLine};
+    use crate::styles::color_logic::ColorSupport;
+    use crossterm::style::Color;
+
+    fn lines(rows: &[&str]) -> Vec<TextLine> {
+        rows.iter()
+            .map(|row| TextLine::new(row.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_memory_backend_shows_the_latest_frame() {
+        let mut renderer = SimpleRenderer::with_backend(MemoryBackend::new(6, 2)).unwrap();
+
+        renderer.render_lines(lines(&["hello", "world"])).unwrap();
+        renderer.render_lines(lines(&["help", ""])).unwrap();
+
+        let backend = renderer.backend();
+        assert_eq!(backend.frames_drawn(), 2);
+        assert_eq!(screen_text(backend.screen()), "help\n");
+    }
+
+    #[test]
+    fn test_memory_backend_resize_repaints_at_new_size() {
+        let mut renderer = SimpleRenderer::with_backend(MemoryBackend::new(4, 1)).unwrap();
+        renderer.render_lines(lines(&["abcd"])).unwrap();
+
+        renderer.backend_mut().set_size(8, 2);
+        renderer.update_size().unwrap();
+        renderer.render_lines(lines(&["abcdefgh", "x"])).unwrap();
+
+        assert_eq!(renderer.get_size(), (8, 2));
+        assert_eq!(screen_text(renderer.backend().screen()), "abcdefgh\nx");
+    }
+
+    #[test]
+    fn test_renderer_background_covers_the_whole_screen() {
+        let mut renderer = SimpleRenderer::with_backend(MemoryBackend::new(4, 2))
+            .unwrap()
+            .with_background(Some(Color::DarkBlue));
+
+        renderer.render_lines(lines(&["hi"])).unwrap();
+
+        let screen = renderer.backend().screen();
+        for (x, y) in [(0, 0), (3, 0), (0, 1), (3, 1)] {
+            assert_eq!(
+                screen.cell(x, y).unwrap().style.bg_color,
+                Some(Color::DarkBlue)
+            );
+        }
+    }
+
+    #[test]
+    fn test_crossterm_backend_wraps_frames_in_synchronized_updates() {
+        let mut output = Vec::new();
+        let mut backend = CrosstermBackend::with_writer(&mut output);
+        let frame = FrameBuffer::from_lines(&lines(&["hi"]), 2, 1);
+
+        backend.draw(&frame, None).unwrap();
+        backend.draw(&frame, Some(&frame)).unwrap();
+
+        let written = String::from_utf8(output).unwrap();
+        assert_eq!(
+            written,
+            "\u{1b}[?2026h\u{1b}[2J\u{1b}[1;1Hhi\u{1b}[?2026l\u{1b}[?2026h\u{1b}[?2026l"
+        );
+    }
+
+    #[test]
+    fn test_crossterm_backend_downsamples_colors() {
+        let frame = FrameBuffer::from_lines(
+            &[TextLine::with_color(
+                "x".to_string(),
+                Color::Rgb {
+                    r: 250,
+                    g: 130,
+                    b: 0,
+                },
+            )],
+            1,
+            1,
+        );
+        let written = |colors: ColorSupport| {
+            let mut output = Vec::new();
+            CrosstermBackend::with_writer(&mut output)
+                .with_color_support(colors)
+                .draw(&frame, Some(&FrameBuffer::new(1, 1)))
+                .unwrap();
+            String::from_utf8(output).unwrap()
+        };
+
+        assert!(written(ColorSupport::TrueColor).contains("\u{1b}[38;2;250;130;0m"));
+        assert!(written(ColorSupport::Ansi256).contains("\u{1b}[38;5;208m"));
+        assert!(written(ColorSupport::Ansi16).contains("\u{1b}[38;5;3m"));
+        assert!(!written(ColorSupport::Mono).contains("\u{1b}[38;"));
+    }
+}
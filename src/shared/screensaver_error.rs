@@ -0,0 +1,63 @@
+use crate::cli::validation_logic::Diagnostic;
+use std::fmt;
+
+// Everything the public API can fail with, so embedding applications can
+// match on the cause and the binary can report it before exiting non-zero
+#[derive(Debug)]
+pub enum ScreensaverError {
+    // The config file could not be read or failed validation
+    Config(Vec<Diagnostic>),
+    // Reading from or drawing to the terminal failed
+    Terminal(std::io::Error),
+    // A configured action could not be started or waited on
+    Action {
+        description: String,
+        source: std::io::Error,
+    },
+    // The requested feature or playlist cannot be created
+    Feature(String),
+    // The logger could not be set up
+    Logger(String),
+}
+
+impl ScreensaverError {
+    pub fn action(description: &str, source: std::io::Error) -> Self {
+        ScreensaverError::Action {
+            description: description.to_string(),
+            source,
+        }
+    }
+}
+
+impl fmt::Display for ScreensaverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScreensaverError::Config(diagnostics) => {
+                let lines: Vec<String> = diagnostics.iter().map(|d| d.to_string()).collect();
+                write!(f, "{}", lines.join("\n"))
+            }
+            ScreensaverError::Terminal(e) => write!(f, "terminal error: {}", e),
+            ScreensaverError::Action {
+                description,
+                source,
+            } => write!(f, "action '{}' failed: {}", description, source),
+            ScreensaverError::Feature(message) => write!(f, "{}", message),
+            ScreensaverError::Logger(message) => write!(f, "cannot set up logging: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ScreensaverError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScreensaverError::Terminal(e) | ScreensaverError::Action { source: e, .. } => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ScreensaverError {
+    fn from(e: std::io::Error) -> Self {
+        ScreensaverError::Terminal(e)
+    }
+}
@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod error_tests {
+    use crate::cli::config_logic::load_config;
+    use crate::shared::screensaver_error::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_unreadable_config_is_a_config_error() {
+        let error = load_config("src").unwrap_err();
+
+        assert!(matches!(&error, ScreensaverError::Config(diagnostics) if diagnostics.len() == 1));
+        assert!(error
+            .to_string()
+            .starts_with("src: error: cannot read config file"));
+    }
+
+    #[test]
+    fn test_io_errors_become_terminal_errors() {
+        let error: ScreensaverError = std::io::Error::other("no tty").into();
+
+        assert!(matches!(error, ScreensaverError::Terminal(_)));
+        assert_eq!(error.to_string(), "terminal error: no tty");
+        assert_eq!(error.source().unwrap().to_string(), "no tty");
+    }
+
+    #[test]
+    fn test_action_errors_name_the_action() {
+        let error = ScreensaverError::action(
+            "Build",
+            std::io::Error::new(std::io::ErrorKind::NotFound, "no such file"),
+        );
+
+        assert_eq!(error.to_string(), "action 'Build' failed: no such file");
+        assert!(error.source().is_some());
+        assert!(ScreensaverError::Feature("unknown".to_string())
+            .source()
+            .is_none());
+    }
+}
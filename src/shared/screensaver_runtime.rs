@@ -0,0 +1,215 @@
+use crate::cli::config_logic::{PlaylistTransition, ScreensaverConfig};
+use crate::features::{ScreensaverAction, ScreensaverFeature};
+use crate::shared::playlist_rotator::{wipe_lines, PlaylistRotator, WIPE_DURATION};
+use crate::shared::{Rect, ScreensaverError, SimpleRenderer, TerminalGuard};
+use crossterm::event::{self, Event, KeyEventKind, MouseEventKind};
+use crossterm::style::Color;
+use std::time::{Duration, Instant};
+
+// Longest step fed to tick, so a stalled frame (e.g. while an action runs)
+// doesn't make animations jump
+const MAX_TICK: Duration = Duration::from_millis(250);
+
+// A feature being wiped away and when the wipe started
+type Outgoing = (Box<dyn ScreensaverFeature>, Instant);
+
+// Drives any ScreensaverFeature: terminal setup and teardown, input polling,
+// resize propagation, rendering and ScreensaverAction handling.
+pub struct ScreensaverRuntime {
+    frame_budget: Duration,
+    dismiss_on_any_key: bool,
+    alternate_screen: bool,
+    mouse_capture: bool,
+    background: Option<Color>,
+}
+
+impl Default for ScreensaverRuntime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScreensaverRuntime {
+    pub fn new() -> Self {
+        Self {
+            frame_budget: Duration::from_millis(50),
+            dismiss_on_any_key: false,
+            alternate_screen: true,
+            mouse_capture: false,
+            background: None,
+        }
+    }
+
+    // Apply the `[screensaver]` terminal settings
+    pub fn with_config(self, config: &ScreensaverConfig) -> Self {
+        self.with_alternate_screen(config.alternate_screen)
+            .with_mouse_capture(config.mouse_capture)
+    }
+
+    // Draw on the alternate screen (the default) or over the main screen
+    pub fn with_alternate_screen(mut self, alternate_screen: bool) -> Self {
+        self.alternate_screen = alternate_screen;
+        self
+    }
+
+    // Take mouse events so clicks and scrolling don't reach the terminal
+    pub fn with_mouse_capture(mut self, mouse_capture: bool) -> Self {
+        self.mouse_capture = mouse_capture;
+        self
+    }
+
+    // Screen background drawn behind every feature, usually the theme's
+    pub fn with_background(mut self, background: Option<Color>) -> Self {
+        self.background = background;
+        self
+    }
+
+    pub fn with_frame_budget(mut self, frame_budget: Duration) -> Self {
+        self.frame_budget = frame_budget;
+        self
+    }
+
+    // Cap rendering at `fps` frames per second
+    pub fn with_fps(self, fps: u32) -> Self {
+        self.with_frame_budget(Duration::from_secs(1) / fps.max(1))
+    }
+
+    // Exit on the first keypress instead of passing keys to the feature
+    pub fn with_dismiss_on_any_key(mut self, dismiss_on_any_key: bool) -> Self {
+        self.dismiss_on_any_key = dismiss_on_any_key;
+        self
+    }
+
+    pub fn run(&self, feature: Box<dyn ScreensaverFeature>) -> Result<(), ScreensaverError> {
+        self.run_session(feature, None)
+    }
+
+    // Rotate through a playlist until the user exits
+    pub fn run_playlist(&self, mut rotator: PlaylistRotator) -> Result<(), ScreensaverError> {
+        let feature = rotator.create_current()?;
+        log::info!("Starting playlist with: {}", rotator.current_name());
+        self.run_session(feature, Some(&mut rotator))
+    }
+
+    fn run_session(
+        &self,
+        feature: Box<dyn ScreensaverFeature>,
+        rotator: Option<&mut PlaylistRotator>,
+    ) -> Result<(), ScreensaverError> {
+        let guard = TerminalGuard::fullscreen(self.alternate_screen, self.mouse_capture)?;
+
+        let result = SimpleRenderer::new()
+            .map(|renderer| renderer.with_background(self.background))
+            .map_err(ScreensaverError::from)
+            .and_then(|mut renderer| self.run_loop(feature, rotator, &mut renderer));
+
+        // Restore the terminal even if the loop failed; a panic restores it on unwind
+        result.and(guard.restore().map_err(ScreensaverError::from))
+    }
+
+    fn run_loop(
+        &self,
+        mut feature: Box<dyn ScreensaverFeature>,
+        mut rotator: Option<&mut PlaylistRotator>,
+        renderer: &mut SimpleRenderer,
+    ) -> Result<(), ScreensaverError> {
+        renderer.clear_screen()?;
+        let (width, height) = renderer.get_size();
+        feature.resize(Rect::from_size(width, height));
+
+        let mut outgoing: Option<Outgoing> = None;
+        let mut last_frame = Instant::now();
+
+        loop {
+            let frame_start = Instant::now();
+            let dt = frame_start.duration_since(last_frame).min(MAX_TICK);
+            last_frame = frame_start;
+            let (width, height) = renderer.get_size();
+            let area = Rect::from_size(width, height);
+
+            if let Some(rotator) = rotator.as_deref_mut() {
+                if rotator.is_due(frame_start) {
+                    outgoing = self.rotate(rotator, &mut feature, &area)?;
+                }
+            }
+
+            feature.tick(dt);
+            let mut lines = feature.render(area.clone());
+            if let Some((previous, started)) = outgoing.as_mut() {
+                let progress = started.elapsed().as_secs_f64() / WIPE_DURATION.as_secs_f64();
+                if progress < 1.0 {
+                    previous.tick(dt);
+                    lines = wipe_lines(previous.render(area.clone()), lines, progress, height);
+                } else {
+                    outgoing = None;
+                }
+            }
+            renderer.render_lines(lines)?;
+
+            // Handle input until this frame's budget is spent
+            while let Some(remaining) = self.frame_budget.checked_sub(frame_start.elapsed()) {
+                if !event::poll(remaining)? {
+                    break;
+                }
+                match event::read()? {
+                    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+                        if self.dismiss_on_any_key {
+                            return Ok(());
+                        }
+                        match feature.handle_input(key_event) {
+                            ScreensaverAction::Continue => {}
+                            ScreensaverAction::Repaint => renderer.invalidate(),
+                            ScreensaverAction::Exit => return Ok(()),
+                            ScreensaverAction::NextScreen => match rotator.as_deref_mut() {
+                                Some(rotator) => {
+                                    outgoing = self.rotate(rotator, &mut feature, &area)?;
+                                    break;
+                                }
+                                None => log::info!(
+                                    "{} requested the next screen, but none is queued",
+                                    feature.name()
+                                ),
+                            },
+                        }
+                    }
+                    Event::Mouse(mouse)
+                        if self.dismiss_on_any_key
+                            && matches!(mouse.kind, MouseEventKind::Down(_)) =>
+                    {
+                        return Ok(());
+                    }
+                    Event::Resize(width, height) => {
+                        renderer.update_size()?;
+                        feature.resize(Rect::from_size(width, height));
+                        if let Some((previous, _)) = outgoing.as_mut() {
+                            previous.resize(Rect::from_size(width, height));
+                        }
+                        // Redraw at the new size straight away
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    // Swap in the next playlist entry, returning the old feature when it should be wiped away
+    fn rotate(
+        &self,
+        rotator: &mut PlaylistRotator,
+        feature: &mut Box<dyn ScreensaverFeature>,
+        area: &Rect,
+    ) -> Result<Option<Outgoing>, ScreensaverError> {
+        let now = Instant::now();
+        log::info!("Rotating to: {}", rotator.advance(now));
+
+        let mut next = rotator.create_current()?;
+        next.resize(area.clone());
+        let previous = std::mem::replace(feature, next);
+
+        Ok(match rotator.transition() {
+            PlaylistTransition::Cut => None,
+            PlaylistTransition::Wipe => Some((previous, now)),
+        })
+    }
+}
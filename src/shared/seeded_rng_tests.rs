@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod rng_tests {
+    use crate::features::{create_feature, ScreensaverFeature};
+    use crate::shared::seeded_rng::*;
+    use crate::shared::snapshot::snapshot_frames;
+    use crate::shared::Rect;
+
+    #[test]
+    fn test_same_seed_gives_same_sequence() {
+        let mut a = SeededRng::new(1234);
+        let mut b = SeededRng::new(1234);
+
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_ne!(SeededRng::new(1).next_u64(), SeededRng::new(2).next_u64());
+    }
+
+    #[test]
+    fn test_ranges_stay_in_bounds() {
+        let mut rng = SeededRng::new(9);
+        let mut seen = [false; 3];
+
+        for _ in 0..1000 {
+            let value = rng.range(1, 3);
+            assert!((1..=3).contains(&value));
+            seen[value as usize - 1] = true;
+
+            let f = rng.next_f64();
+            assert!((0.0..1.0).contains(&f));
+            assert!(rng.index(5) < 5);
+        }
+        assert!(
+            seen.iter().all(|s| *s),
+            "range(1, 3) should reach both ends"
+        );
+    }
+
+    #[test]
+    fn test_fork_is_deterministic_and_independent() {
+        let mut parent = SeededRng::new(5);
+        let mut first = parent.fork();
+        let mut second = parent.fork();
+        assert_ne!(first.next_u64(), second.next_u64());
+
+        let mut again = SeededRng::new(5).fork();
+        assert_eq!(SeededRng::new(5).fork().next_u64(), again.next_u64());
+    }
+
+    #[test]
+    fn test_seeded_features_render_identically() {
+        let config = crate::cli::config_logic::Config::default();
+        let area = Rect::new(0, 0, 40, 10);
+
+        for name in ["matrix_rain", "starfield", "bouncing_logo"] {
+            let render = |seed: u64| {
+                let mut feature: Box<dyn ScreensaverFeature> =
+                    create_feature(name, &config, &mut SeededRng::new(seed)).unwrap();
+                snapshot_frames(feature.as_mut(), area.clone(), 5)
+            };
+            assert_eq!(render(77), render(77), "{} is not reproducible", name);
+        }
+    }
+}
@@ -1,11 +1,26 @@
-use crossterm::execute;
-use crossterm::terminal::{size, Clear, ClearType};
-use std::io::stdout;
+use crate::shared::ScreensaverError;
+use crossterm::cursor::MoveTo;
+use crossterm::style::Print;
+use crossterm::terminal::size;
+use crossterm::QueueableCommand;
+use std::io::{stdout, Write};
 
-pub fn clear_screen() {
-    execute!(stdout(), Clear(ClearType::All)).unwrap();
+// Clear the screen without destroying what the user was looking at: the
+// visible lines are scrolled up into the scrollback first
+pub fn clear_screen() -> Result<(), ScreensaverError> {
+    let (_, height) = size()?;
+    Ok(push_to_scrollback(&mut stdout(), height)?)
 }
 
-pub fn get_terminal_size() -> (u16, u16) {
-    size().unwrap()
+pub fn get_terminal_size() -> Result<(u16, u16), ScreensaverError> {
+    Ok(size()?)
+}
+
+// Scroll `height` lines off the top of the screen, which terminals keep in
+// their scrollback, and leave the cursor at the top left of the blank screen
+pub fn push_to_scrollback(out: &mut impl Write, height: u16) -> std::io::Result<()> {
+    out.queue(MoveTo(0, height.saturating_sub(1)))?
+        .queue(Print("\n".repeat(height as usize)))?
+        .queue(MoveTo(0, 0))?;
+    out.flush()
 }
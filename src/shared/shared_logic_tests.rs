@@ -0,0 +1,23 @@
+#[cfg(test)]
+mod shared_tests {
+    use crate::shared::shared_logic::*;
+
+    #[test]
+    fn test_push_to_scrollback_scrolls_every_visible_line() {
+        let mut out = Vec::new();
+        push_to_scrollback(&mut out, 3).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\u{1b}[3;1H\n\n\n\u{1b}[1;1H"
+        );
+    }
+
+    #[test]
+    fn test_push_to_scrollback_handles_zero_height() {
+        let mut out = Vec::new();
+        push_to_scrollback(&mut out, 0).unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), "\u{1b}[1;1H\u{1b}[1;1H");
+    }
+}
@@ -1,97 +1,179 @@
-use crossterm::{
-    cursor,
-    style::{Color, Print, SetForegroundColor},
-    terminal::{Clear, ClearType},
-    ExecutableCommand, QueueableCommand,
-};
-use std::io::{stdout, Write};
-
-pub struct SimpleRenderer {
+use crate::shared::frame_buffer::FrameBuffer;
+use crate::shared::render_backend::{CrosstermBackend, RenderBackend};
+use crate::shared::text_width::{display_width, grapheme_width, graphemes};
+use crate::styles::style_logic::Style;
+use crossterm::style::Color;
+
+pub struct SimpleRenderer<B: RenderBackend = CrosstermBackend> {
+    backend: B,
     width: u16,
     height: u16,
+    background: Option<Color>,
+    previous: Option<FrameBuffer>,
 }
 
-#[derive(Clone)]
-pub struct TextLine {
+// A run of text drawn with a single style
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextSpan {
     pub content: String,
-    pub color: Option<Color>,
+    pub style: Style,
 }
 
-impl TextLine {
-    pub fn new(content: String) -> Self {
+impl TextSpan {
+    pub fn new(content: impl Into<String>) -> Self {
+        Self::styled(content, Style::new())
+    }
+
+    pub fn styled(content: impl Into<String>, style: Style) -> Self {
         Self {
-            content,
-            color: None,
+            content: content.into(),
+            style,
         }
     }
+}
+
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct TextLine {
+    pub spans: Vec<TextSpan>,
+}
+
+impl TextLine {
+    pub fn new(content: String) -> Self {
+        Self::from_spans(vec![TextSpan::new(content)])
+    }
 
     pub fn with_color(content: String, color: Color) -> Self {
-        Self {
-            content,
-            color: Some(color),
+        Self::styled(content, Style::new().fg(color))
+    }
+
+    pub fn styled(content: String, style: Style) -> Self {
+        Self::from_spans(vec![TextSpan::styled(content, style)])
+    }
+
+    pub fn from_spans(spans: Vec<TextSpan>) -> Self {
+        Self { spans }
+    }
+
+    pub fn push(&mut self, span: TextSpan) {
+        self.spans.push(span);
+    }
+
+    // Append text, extending the last span when it already has the same style
+    pub fn push_styled(&mut self, content: &str, style: &Style) {
+        match self.spans.last_mut() {
+            Some(last) if last.style == *style => last.content.push_str(content),
+            _ => self.spans.push(TextSpan::styled(content, style.clone())),
         }
     }
+
+    // Plain text of the line with all styling dropped
+    pub fn content(&self) -> String {
+        self.spans
+            .iter()
+            .map(|span| span.content.as_str())
+            .collect()
+    }
+
+    // This line with `top` drawn over it starting at column `x`. Short lines
+    // are padded out to `x`, and a wide glyph cut by either edge becomes blanks.
+    pub fn overlay(&self, x: usize, top: &TextLine) -> TextLine {
+        let end = x + display_width(&top.content());
+        let mut before = TextLine::default();
+        let mut after = TextLine::default();
+        let mut column = 0;
+
+        for span in &self.spans {
+            for grapheme in graphemes(&span.content) {
+                let width = grapheme_width(grapheme);
+                let start = column;
+                column += width;
+
+                if column <= x {
+                    before.push_styled(grapheme, &span.style);
+                } else if start < x {
+                    before.push_styled(&" ".repeat(x - start), &span.style);
+                }
+                // Zero-width marks at `end` belong to a glyph hidden under `top`
+                if start > end || (start == end && width > 0) {
+                    after.push_styled(grapheme, &span.style);
+                } else if start < end && column > end {
+                    after.push_styled(&" ".repeat(column - end), &span.style);
+                }
+            }
+        }
+
+        before.push_styled(&" ".repeat(x.saturating_sub(column)), &Style::new());
+        let mut line = before;
+        for span in top.spans.iter().chain(after.spans.iter()) {
+            line.push_styled(&span.content, &span.style);
+        }
+        line.spans.retain(|span| !span.content.is_empty());
+        line
+    }
 }
 
 impl SimpleRenderer {
     pub fn new() -> std::io::Result<Self> {
-        let (width, height) = crossterm::terminal::size()?;
-        Ok(Self { width, height })
+        Self::with_backend(CrosstermBackend::new())
     }
+}
 
-    pub fn clear_screen(&mut self) -> std::io::Result<()> {
-        stdout()
-            .execute(Clear(ClearType::All))?
-            .execute(cursor::MoveTo(0, 0))?;
-        Ok(())
+impl<B: RenderBackend> SimpleRenderer<B> {
+    pub fn with_backend(backend: B) -> std::io::Result<Self> {
+        let (width, height) = backend.size()?;
+        Ok(Self {
+            backend,
+            width,
+            height,
+            background: None,
+            previous: None,
+        })
     }
 
-    pub fn render_lines(&mut self, lines: Vec<TextLine>) -> std::io::Result<()> {
-        let mut stdout = stdout();
+    // Fill the whole screen with `background` behind anything drawn without
+    // a background color; None leaves the terminal's own background
+    pub fn with_background(mut self, background: Option<Color>) -> Self {
+        self.background = background;
+        self
+    }
 
-        // Clear screen and move to top
-        stdout
-            .queue(Clear(ClearType::All))?
-            .queue(cursor::MoveTo(0, 0))?;
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
 
-        // Render each line
-        for (y, line) in lines.iter().enumerate() {
-            if y >= self.height as usize {
-                break;
-            }
+    pub fn backend_mut(&mut self) -> &mut B {
+        &mut self.backend
+    }
 
-            stdout.queue(cursor::MoveTo(0, y as u16))?;
+    // Clears the screen being drawn on; TerminalGuard::fullscreen has already
+    // switched to the alternate screen or saved the main screen's lines
+    pub fn clear_screen(&mut self) -> std::io::Result<()> {
+        self.backend.clear()?;
+        self.invalidate();
+        Ok(())
+    }
 
-            if let Some(color) = line.color {
-                stdout.queue(SetForegroundColor(color))?;
-            }
+    // Forget the previous frame so the next render repaints every cell
+    pub fn invalidate(&mut self) {
+        self.previous = None;
+    }
 
-            // Truncate line if it's too long (Unicode-safe)
-            let content = if line.content.chars().count() > self.width as usize {
-                line.content
-                    .chars()
-                    .take(self.width as usize)
-                    .collect::<String>()
-            } else {
-                line.content.clone()
-            };
-
-            stdout.queue(Print(&content))?;
-
-            // Reset color if it was set
-            if line.color.is_some() {
-                stdout.queue(SetForegroundColor(Color::Reset))?;
-            }
+    pub fn render_lines(&mut self, lines: Vec<TextLine>) -> std::io::Result<()> {
+        let mut frame = FrameBuffer::from_lines(&lines, self.width, self.height);
+        if let Some(background) = self.background {
+            frame.fill_background(background);
         }
-
-        stdout.flush()?;
+        self.backend.draw(&frame, self.previous.as_ref())?;
+        self.previous = Some(frame);
         Ok(())
     }
 
     pub fn update_size(&mut self) -> std::io::Result<()> {
-        let (width, height) = crossterm::terminal::size()?;
+        let (width, height) = self.backend.size()?;
         self.width = width;
         self.height = height;
+        // The terminal may have reflowed, so the old frame can't be trusted
+        self.invalidate();
         Ok(())
     }
 
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod text_line_tests {
+    use crate::shared::simple_renderer::*;
+    use crate::styles::style_logic::Style;
+    use crossterm::style::{Attribute, Color};
+
+    #[test]
+    fn test_plain_and_colored_lines_have_one_span() {
+        let plain = TextLine::new("hello".to_string());
+        assert_eq!(plain.spans.len(), 1);
+        assert_eq!(plain.spans[0].style, Style::new());
+
+        let colored = TextLine::with_color("hello".to_string(), Color::Red);
+        assert_eq!(colored.spans[0].style.color, Some(Color::Red));
+        assert_eq!(colored.content(), "hello");
+    }
+
+    #[test]
+    fn test_push_styled_merges_runs_of_the_same_style() {
+        let red = Style::new().fg(Color::Red);
+        let blue = Style::new().fg(Color::Blue).bold();
+        let mut line = TextLine::default();
+
+        line.push_styled("a", &red);
+        line.push_styled("b", &red);
+        line.push_styled("c", &blue);
+        line.push_styled("d", &red);
+
+        assert_eq!(line.spans.len(), 3);
+        assert_eq!(line.spans[0].content, "ab");
+        assert_eq!(line.spans[1].style.attributes, vec![Attribute::Bold]);
+        assert_eq!(line.content(), "abcd");
+    }
+
+    #[test]
+    fn test_style_attributes_are_not_duplicated() {
+        let style = Style::new()
+            .bg(Color::Black)
+            .bold()
+            .dim()
+            .italic()
+            .underline()
+            .bold();
+
+        assert_eq!(style.bg_color, Some(Color::Black));
+        assert_eq!(
+            style.attributes,
+            vec![
+                Attribute::Bold,
+                Attribute::Dim,
+                Attribute::Italic,
+                Attribute::Underlined
+            ]
+        );
+    }
+
+    #[test]
+    fn test_overlay_replaces_the_covered_columns() {
+        let red = Style::new().fg(Color::Red);
+        let base = TextLine::styled("abcdefgh".to_string(), red.clone());
+        let top = TextLine::with_color("XY".to_string(), Color::Blue);
+
+        let line = base.overlay(3, &top);
+        assert_eq!(line.content(), "abcXYfgh");
+        assert_eq!(line.spans[0].style, red);
+        assert_eq!(line.spans[1].style.color, Some(Color::Blue));
+        assert_eq!(line.spans[2].content, "fgh");
+    }
+
+    #[test]
+    fn test_overlay_pads_short_lines() {
+        let line = TextLine::new("ab".to_string()).overlay(5, &TextLine::new("XY".to_string()));
+        assert_eq!(line.content(), "ab   XY");
+    }
+
+    #[test]
+    fn test_overlay_blanks_wide_glyphs_cut_by_an_edge() {
+        // Each of these glyphs is two columns wide
+        let base = TextLine::new("\u{30a2}\u{30a4}\u{30a6}".to_string());
+
+        let line = base.overlay(1, &TextLine::new("XY".to_string()));
+        assert_eq!(line.content(), " XY \u{30a6}");
+    }
+}
@@ -0,0 +1,81 @@
+use crate::features::{ScreensaverFeature, REFERENCE_FRAME};
+use crate::shared::{FrameBuffer, MemoryBackend, Rect, SimpleRenderer};
+use std::path::PathBuf;
+
+// Snapshot-test helpers: render a feature headlessly and compare the screen
+// text with fixtures in tests/snapshots. Run with UPDATE_SNAPSHOTS=1 to
+// (re)write the fixtures instead of comparing.
+
+// Render `frames` frames of `feature` at `area`, each one reference frame apart,
+// returning the screen after each one
+pub fn render_frames(
+    feature: &mut dyn ScreensaverFeature,
+    area: Rect,
+    frames: usize,
+) -> Vec<FrameBuffer> {
+    let backend = MemoryBackend::new(area.width, area.height);
+    let mut renderer = SimpleRenderer::with_backend(backend)
+        .expect("the in-memory backend always reports its size");
+
+    feature.resize(area.clone());
+    (0..frames)
+        .map(|_| {
+            feature.tick(REFERENCE_FRAME);
+            renderer
+                .render_lines(feature.render(area.clone()))
+                .expect("the in-memory backend never fails to draw");
+            renderer.backend().screen().clone()
+        })
+        .collect()
+}
+
+// Screen text with trailing spaces trimmed from every row
+pub fn screen_text(screen: &FrameBuffer) -> String {
+    (0..screen.height)
+        .map(|y| screen.row_text(y).trim_end().to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// All frames as one text block, each under a `--- frame N ---` header
+pub fn snapshot_frames(feature: &mut dyn ScreensaverFeature, area: Rect, frames: usize) -> String {
+    render_frames(feature, area, frames)
+        .iter()
+        .enumerate()
+        .map(|(i, screen)| format!("--- frame {} ---\n{}\n", i + 1, screen_text(screen)))
+        .collect()
+}
+
+pub fn snapshot_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{}.txt", name))
+}
+
+// Compare `actual` with the stored fixture `name`, panicking with both on mismatch
+pub fn assert_snapshot(name: &str, actual: &str) {
+    let path = snapshot_path(name);
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).expect("create snapshot directory");
+        }
+        std::fs::write(&path, actual).expect("write snapshot");
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "missing snapshot {}; run with UPDATE_SNAPSHOTS=1 to create it",
+            path.display()
+        )
+    });
+    assert!(
+        expected == actual,
+        "snapshot {} does not match\n--- expected ---\n{}\n--- actual ---\n{}",
+        path.display(),
+        expected,
+        actual
+    );
+}
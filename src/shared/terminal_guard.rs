@@ -0,0 +1,182 @@
+#[cfg(unix)]
+use crate::shared::action_runner;
+use crate::shared::shared_logic;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+use crossterm::execute;
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, is_raw_mode_enabled, size, Clear, ClearType,
+    EnterAlternateScreen, LeaveAlternateScreen,
+};
+#[cfg(unix)]
+use signal_hook::consts::{SIGHUP, SIGINT, SIGTERM};
+#[cfg(unix)]
+use signal_hook::iterator::Signals;
+use std::io::{stdout, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// What the active guards changed, so the panic hook and signal handler can
+// undo it without access to the guards themselves
+static RAW_MODE_ENTERED: AtomicBool = AtomicBool::new(false);
+static ALTERNATE_SCREEN_ENTERED: AtomicBool = AtomicBool::new(false);
+static MAIN_SCREEN_USED: AtomicBool = AtomicBool::new(false);
+static MOUSE_CAPTURED: AtomicBool = AtomicBool::new(false);
+
+// Puts the terminal into the requested state and restores it when dropped,
+// including while unwinding from a panic. Guards nest: an inner guard only
+// undoes what it changed itself.
+#[derive(Default)]
+pub struct TerminalGuard {
+    disable_raw: bool,
+    leave_alternate_screen: bool,
+    clear_main_screen: bool,
+    disable_mouse_capture: bool,
+}
+
+impl TerminalGuard {
+    // Raw mode only, for waiting on input without taking over the screen
+    pub fn raw() -> std::io::Result<Self> {
+        let was_raw = is_raw_mode_enabled()?;
+        if !was_raw {
+            enable_raw_mode()?;
+            RAW_MODE_ENTERED.store(true, Ordering::SeqCst);
+        }
+        Ok(Self {
+            disable_raw: !was_raw,
+            ..Self::default()
+        })
+    }
+
+    // Raw mode and a hidden cursor for drawing a feature. The alternate screen
+    // keeps the user's screen intact; without it the visible lines are pushed
+    // into the scrollback before drawing over the main screen.
+    pub fn fullscreen(alternate_screen: bool, mouse_capture: bool) -> std::io::Result<Self> {
+        let mut guard = Self::raw()?;
+        guard.claim_screen(alternate_screen, mouse_capture);
+        if guard.leave_alternate_screen {
+            execute!(stdout(), EnterAlternateScreen)?;
+        }
+        if guard.clear_main_screen {
+            let (_, height) = size()?;
+            shared_logic::push_to_scrollback(&mut stdout(), height)?;
+        }
+        if guard.disable_mouse_capture {
+            execute!(stdout(), EnableMouseCapture)?;
+        }
+        execute!(stdout(), Hide)?;
+        Ok(guard)
+    }
+
+    // Take on the screen changes no outer guard has made yet, so this guard
+    // is the one that undoes them
+    pub(crate) fn claim_screen(&mut self, alternate_screen: bool, mouse_capture: bool) {
+        if alternate_screen {
+            self.leave_alternate_screen = !ALTERNATE_SCREEN_ENTERED.swap(true, Ordering::SeqCst);
+        } else if !ALTERNATE_SCREEN_ENTERED.load(Ordering::SeqCst) {
+            self.clear_main_screen = !MAIN_SCREEN_USED.swap(true, Ordering::SeqCst);
+        }
+        if mouse_capture {
+            self.disable_mouse_capture = !MOUSE_CAPTURED.swap(true, Ordering::SeqCst);
+        }
+    }
+
+    // Restore now, reporting errors that dropping the guard would swallow
+    pub fn restore(mut self) -> std::io::Result<()> {
+        self.undo_on(&mut stdout())
+    }
+
+    // Undo this guard's changes, skipping any the panic hook or signal
+    // handler has already restored
+    pub(crate) fn undo_on(&mut self, out: &mut impl Write) -> std::io::Result<()> {
+        let mut result = execute!(out, Show);
+        if std::mem::take(&mut self.disable_mouse_capture)
+            && MOUSE_CAPTURED.swap(false, Ordering::SeqCst)
+        {
+            result = result.and(execute!(out, DisableMouseCapture));
+        }
+        if std::mem::take(&mut self.clear_main_screen)
+            && MAIN_SCREEN_USED.swap(false, Ordering::SeqCst)
+        {
+            result = result.and(execute!(out, Clear(ClearType::All), MoveTo(0, 0)));
+        }
+        if std::mem::take(&mut self.leave_alternate_screen)
+            && ALTERNATE_SCREEN_ENTERED.swap(false, Ordering::SeqCst)
+        {
+            result = result.and(execute!(out, LeaveAlternateScreen));
+        }
+        if std::mem::take(&mut self.disable_raw) && RAW_MODE_ENTERED.swap(false, Ordering::SeqCst) {
+            result = result.and(disable_raw_mode());
+        }
+        result
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = self.undo_on(&mut stdout());
+    }
+}
+
+// Whether a guard currently owns the terminal, so nothing else should print
+pub fn is_active() -> bool {
+    RAW_MODE_ENTERED.load(Ordering::SeqCst)
+        || ALTERNATE_SCREEN_ENTERED.load(Ordering::SeqCst)
+        || MAIN_SCREEN_USED.load(Ordering::SeqCst)
+}
+
+// Best-effort restore of everything the guards changed, for paths where the
+// guards won't be dropped (signals) or drop too late (panic messages)
+pub fn restore_terminal() {
+    restore_terminal_on(&mut stdout());
+}
+
+pub(crate) fn restore_terminal_on(out: &mut impl Write) {
+    let _ = execute!(out, Show);
+    if MOUSE_CAPTURED.swap(false, Ordering::SeqCst) {
+        let _ = execute!(out, DisableMouseCapture);
+    }
+    if MAIN_SCREEN_USED.swap(false, Ordering::SeqCst) {
+        let _ = execute!(out, Clear(ClearType::All), MoveTo(0, 0));
+    }
+    if ALTERNATE_SCREEN_ENTERED.swap(false, Ordering::SeqCst) {
+        let _ = execute!(out, LeaveAlternateScreen);
+    }
+    if RAW_MODE_ENTERED.swap(false, Ordering::SeqCst) {
+        let _ = disable_raw_mode();
+    }
+}
+
+// Restore the terminal before the panic message is printed, so it lands on
+// the main screen and the shell is usable afterwards
+pub fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        restore_terminal();
+        previous(info);
+    }));
+}
+
+// Stop running actions, restore the terminal and exit on SIGINT, SIGTERM and
+// SIGHUP. Raw mode keeps Ctrl+C from raising SIGINT, but kill and closing the
+// terminal still can.
+#[cfg(unix)]
+pub fn install_signal_handlers() -> std::io::Result<()> {
+    let mut signals = Signals::new([SIGINT, SIGTERM, SIGHUP])?;
+    std::thread::spawn(move || {
+        if let Some(signal) = signals.forever().next() {
+            // Exiting skips destructors, so stop running actions here
+            action_runner::stop_running_actions();
+            restore_terminal();
+            log::info!("Received signal {}, exiting", signal);
+            std::process::exit(128 + signal);
+        }
+    });
+    Ok(())
+}
+
+// Other platforms have no signals to catch: in raw mode Ctrl+C arrives as a
+// key event, and the panic hook and guards cover every other exit
+#[cfg(not(unix))]
+pub fn install_signal_handlers() -> std::io::Result<()> {
+    Ok(())
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod guard_tests {
+    use crate::shared::terminal_guard::*;
+    use crossterm::cursor::MoveTo;
+    use crossterm::event::DisableMouseCapture;
+    use crossterm::execute;
+    use crossterm::terminal::{Clear, ClearType, LeaveAlternateScreen};
+    use std::sync::Mutex;
+
+    // Guards share global state, so these tests take turns
+    static GUARD_STATE: Mutex<()> = Mutex::new(());
+
+    fn contains(output: &[u8], sequence: &[u8]) -> bool {
+        output
+            .windows(sequence.len())
+            .any(|window| window == sequence)
+    }
+
+    #[test]
+    fn test_panic_hook_and_drop_restore_only_once() {
+        let _lock = GUARD_STATE.lock().unwrap();
+        let mut clear = Vec::new();
+        execute!(clear, Clear(ClearType::All), MoveTo(0, 0)).unwrap();
+        let mut leave = Vec::new();
+        execute!(leave, LeaveAlternateScreen).unwrap();
+        let mut mouse = Vec::new();
+        execute!(mouse, DisableMouseCapture).unwrap();
+
+        for (alternate_screen, screen) in [(false, &clear), (true, &leave)] {
+            let mut guard = TerminalGuard::default();
+            guard.claim_screen(alternate_screen, true);
+            assert!(is_active());
+
+            // The panic hook runs first, then unwinding drops the guard
+            let mut hook = Vec::new();
+            restore_terminal_on(&mut hook);
+            let mut dropped = Vec::new();
+            guard.undo_on(&mut dropped).unwrap();
+
+            assert!(contains(&hook, screen));
+            assert!(contains(&hook, &mouse));
+            assert!(!contains(&dropped, screen));
+            assert!(!contains(&dropped, &mouse));
+            assert!(!is_active());
+        }
+    }
+
+    #[test]
+    fn test_nested_guard_leaves_the_outer_guards_changes() {
+        let _lock = GUARD_STATE.lock().unwrap();
+        let mut outer = TerminalGuard::default();
+        outer.claim_screen(true, false);
+        let mut inner = TerminalGuard::default();
+        inner.claim_screen(true, false);
+
+        let mut leave = Vec::new();
+        execute!(leave, LeaveAlternateScreen).unwrap();
+        let mut output = Vec::new();
+        inner.undo_on(&mut output).unwrap();
+        assert!(!contains(&output, &leave));
+
+        output.clear();
+        outer.undo_on(&mut output).unwrap();
+        assert!(contains(&output, &leave));
+    }
+}
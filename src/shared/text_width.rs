@@ -0,0 +1,98 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+// Layout works in terminal columns, not bytes or chars: CJK characters take
+// two columns, combining marks none, and an emoji sequence joined with ZWJ
+// or a skin tone modifier is one two-column glyph. Text is measured per
+// grapheme cluster so all of these line up with what the terminal draws.
+
+// The user-perceived characters of `text`, each drawn as one glyph
+pub fn graphemes(text: &str) -> impl Iterator<Item = &str> {
+    text.graphemes(true)
+}
+
+// Columns one grapheme takes; control characters take none
+pub fn grapheme_width(grapheme: &str) -> usize {
+    if grapheme.chars().all(char::is_control) {
+        0
+    } else {
+        grapheme.width()
+    }
+}
+
+// Columns `text` takes on screen
+pub fn display_width(text: &str) -> usize {
+    graphemes(text).map(grapheme_width).sum()
+}
+
+// The longest prefix of `text` that fits in `width` columns, never
+// splitting a grapheme
+pub fn truncate_to_width(text: &str, width: usize) -> &str {
+    let mut used = 0;
+    for (offset, grapheme) in text.grapheme_indices(true) {
+        used += grapheme_width(grapheme);
+        if used > width {
+            return &text[..offset];
+        }
+    }
+    text
+}
+
+// Columns to skip so `text` sits centered in `width`
+pub fn center_offset(text: &str, width: usize) -> usize {
+    width.saturating_sub(display_width(text)) / 2
+}
+
+// `text` with leading spaces that center it in `width`; text that doesn't
+// fit is returned unchanged
+pub fn center_text(text: &str, width: usize) -> String {
+    format!("{}{}", " ".repeat(center_offset(text, width)), text)
+}
+
+// `text` split into rows at most `width` columns wide: one row per line of
+// the input, with lines that don't fit wrapped between words. Words wider
+// than `width` are broken between graphemes.
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    if width == 0 {
+        return Vec::new();
+    }
+
+    let mut rows = Vec::new();
+    for line in text.lines() {
+        // Lines that fit keep their spacing, so aligned art survives
+        if display_width(line) <= width {
+            rows.push(line.to_string());
+            continue;
+        }
+
+        let mut row = String::new();
+        for word in line.split_whitespace() {
+            let mut word = word;
+            let needed = display_width(word) + if row.is_empty() { 0 } else { 1 };
+            if display_width(&row) + needed <= width {
+                if !row.is_empty() {
+                    row.push(' ');
+                }
+                row.push_str(word);
+                continue;
+            }
+            if !row.is_empty() {
+                rows.push(std::mem::take(&mut row));
+            }
+            while display_width(word) > width {
+                let mut head = truncate_to_width(word, width);
+                // A glyph wider than the whole row still has to go somewhere
+                if head.is_empty() {
+                    head = graphemes(word).next().unwrap_or(word);
+                }
+                rows.push(head.to_string());
+                word = &word[head.len()..];
+            }
+            row.push_str(word);
+        }
+        if !row.is_empty() {
+            rows.push(row);
+        }
+    }
+    rows
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod width_tests {
+    use crate::shared::text_width::*;
+
+    // Family emoji: man, woman and girl joined with ZWJ
+    const FAMILY: &str = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+
+    #[test]
+    fn test_width_counts_columns_not_bytes() {
+        assert_eq!(display_width("hello"), 5);
+        // Japanese text is two columns per character
+        assert_eq!(display_width("\u{65E5}\u{672C}\u{8A9E}"), 6);
+        // e with a combining acute accent
+        assert_eq!(display_width("e\u{301}"), 1);
+        assert_eq!(display_width("a\u{200B}b"), 2);
+        assert_eq!(display_width("tab\there"), 7);
+    }
+
+    #[test]
+    fn test_joined_emoji_are_one_glyph() {
+        assert_eq!(display_width(FAMILY), 2);
+        assert_eq!(graphemes(FAMILY).count(), 1);
+        // Thumbs up with a skin tone modifier
+        assert_eq!(display_width("\u{1F44D}\u{1F3FD}"), 2);
+        // Heart with the emoji presentation selector
+        assert_eq!(display_width("\u{2764}\u{FE0F}"), 2);
+    }
+
+    #[test]
+    fn test_truncation_never_splits_a_glyph() {
+        let text = "a\u{65E5}\u{672C}";
+        assert_eq!(truncate_to_width(text, 2), "a");
+        assert_eq!(truncate_to_width(text, 3), "a\u{65E5}");
+        assert_eq!(truncate_to_width(text, 10), text);
+        assert_eq!(truncate_to_width(&format!("{}x", FAMILY), 1), "");
+        assert_eq!(truncate_to_width("e\u{301}x", 1), "e\u{301}");
+    }
+
+    #[test]
+    fn test_centering_uses_display_width() {
+        assert_eq!(center_offset("\u{65E5}\u{672C}", 10), 3);
+        assert_eq!(center_text("\u{65E5}\u{672C}", 10), "   \u{65E5}\u{672C}");
+        assert_eq!(center_text("too wide", 4), "too wide");
+    }
+
+    #[test]
+    fn test_wrapping_breaks_between_words() {
+        assert_eq!(
+            wrap_text("the quick brown fox", 10),
+            vec!["the quick", "brown fox"]
+        );
+        assert_eq!(wrap_text("one\n\ntwo", 10), vec!["one", "", "two"]);
+        // Lines that fit keep their spacing
+        assert_eq!(wrap_text(" |  |", 10), vec![" |  |"]);
+        assert!(wrap_text("anything", 0).is_empty());
+    }
+
+    #[test]
+    fn test_wrapping_splits_long_words_by_width() {
+        assert_eq!(wrap_text("abcdefgh ij", 3), vec!["abc", "def", "gh", "ij"]);
+        assert_eq!(
+            wrap_text("\u{65E5}\u{672C}\u{8A9E}", 4),
+            vec!["\u{65E5}\u{672C}", "\u{8A9E}"]
+        );
+        // A wide glyph still gets a row of its own when nothing fits
+        assert_eq!(
+            wrap_text("\u{65E5}\u{672C}", 1),
+            vec!["\u{65E5}", "\u{672C}"]
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use crossterm::style::Color;
+
+// How many colors the terminal can show, from fewest to most
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorSupport {
+    Mono,
+    Ansi16,
+    Ansi256,
+    TrueColor,
+}
+
+impl ColorSupport {
+    // Detected from NO_COLOR, COLORTERM and TERM
+    pub fn detect() -> Self {
+        detect_color_support(
+            std::env::var("NO_COLOR").ok().as_deref(),
+            std::env::var("COLORTERM").ok().as_deref(),
+            std::env::var("TERM").ok().as_deref(),
+        )
+    }
+}
+
+// NO_COLOR (any non-empty value) wins, then COLORTERM announcing 24-bit
+// color, then what TERM's name promises. Unknown terminals get 16 colors.
+pub fn detect_color_support(
+    no_color: Option<&str>,
+    colorterm: Option<&str>,
+    term: Option<&str>,
+) -> ColorSupport {
+    if no_color.is_some_and(|value| !value.is_empty()) {
+        return ColorSupport::Mono;
+    }
+    if let Some("truecolor" | "24bit") = colorterm.map(|value| value.to_lowercase()).as_deref() {
+        return ColorSupport::TrueColor;
+    }
+
+    let term = term.unwrap_or_default().to_lowercase();
+    if term == "dumb" {
+        ColorSupport::Mono
+    } else if ["truecolor", "24bit", "direct"]
+        .iter()
+        .any(|name| term.contains(name))
+    {
+        ColorSupport::TrueColor
+    } else if term.contains("256color") {
+        ColorSupport::Ansi256
+    } else {
+        ColorSupport::Ansi16
+    }
+}
+
+// The 16 named colors in ANSI index order, with the RGB values xterm uses
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::DarkRed, (128, 0, 0)),
+    (Color::DarkGreen, (0, 128, 0)),
+    (Color::DarkYellow, (128, 128, 0)),
+    (Color::DarkBlue, (0, 0, 128)),
+    (Color::DarkMagenta, (128, 0, 128)),
+    (Color::DarkCyan, (0, 128, 128)),
+    (Color::Grey, (192, 192, 192)),
+    (Color::DarkGrey, (128, 128, 128)),
+    (Color::Red, (255, 0, 0)),
+    (Color::Green, (0, 255, 0)),
+    (Color::Yellow, (255, 255, 0)),
+    (Color::Blue, (0, 0, 255)),
+    (Color::Magenta, (255, 0, 255)),
+    (Color::Cyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+// Channel levels of the 6x6x6 cube in the 256-color palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+// `color` as the closest color `support` can show; None when the terminal
+// should get no color at all
+pub fn downsample(color: Color, support: ColorSupport) -> Option<Color> {
+    match (support, color) {
+        (ColorSupport::Mono, _) => None,
+        (ColorSupport::TrueColor, color) => Some(color),
+        (ColorSupport::Ansi256, Color::Rgb { r, g, b }) => Some(Color::AnsiValue(ansi256(r, g, b))),
+        (ColorSupport::Ansi16, Color::Rgb { r, g, b }) => Some(ansi16(r, g, b)),
+        (ColorSupport::Ansi16, Color::AnsiValue(index)) => {
+            let (r, g, b) = ansi256_rgb(index);
+            Some(ansi16(r, g, b))
+        }
+        (_, color) => Some(color),
+    }
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let channel = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+    channel(a.0, b.0) + channel(a.1, b.1) + channel(a.2, b.2)
+}
+
+fn ansi16(r: u8, g: u8, b: u8) -> Color {
+    ANSI16
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb, (r, g, b)))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::White)
+}
+
+// Nearest entry of the color cube or the grey ramp (232-255)
+fn ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |c: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&i| (CUBE_LEVELS[i] as i32 - c as i32).abs())
+            .unwrap_or(0) as u8
+    };
+    let (ri, gi, bi) = (level(r), level(g), level(b));
+    let cube = (
+        CUBE_LEVELS[ri as usize],
+        CUBE_LEVELS[gi as usize],
+        CUBE_LEVELS[bi as usize],
+    );
+
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let grey = 8 + 10 * step;
+
+    if distance((grey, grey, grey), (r, g, b)) < distance(cube, (r, g, b)) {
+        232 + step
+    } else {
+        16 + 36 * ri + 6 * gi + bi
+    }
+}
+
+fn ansi256_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI16[index as usize].1,
+        16..=231 => {
+            let cube = index - 16;
+            (
+                CUBE_LEVELS[(cube / 36) as usize],
+                CUBE_LEVELS[(cube / 6 % 6) as usize],
+                CUBE_LEVELS[(cube % 6) as usize],
+            )
+        }
+        _ => {
+            let grey = 8 + 10 * (index - 232);
+            (grey, grey, grey)
+        }
+    }
+}
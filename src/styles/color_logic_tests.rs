@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod color_tests {
+    use crate::styles::color_logic::*;
+    use crossterm::style::Color;
+
+    fn rgb(r: u8, g: u8, b: u8) -> Color {
+        Color::Rgb { r, g, b }
+    }
+
+    #[test]
+    fn test_no_color_disables_colors() {
+        assert_eq!(
+            detect_color_support(Some("1"), Some("truecolor"), Some("xterm-256color")),
+            ColorSupport::Mono
+        );
+        // An empty NO_COLOR doesn't count
+        assert_eq!(
+            detect_color_support(Some(""), None, Some("xterm-256color")),
+            ColorSupport::Ansi256
+        );
+    }
+
+    #[test]
+    fn test_support_is_detected_from_colorterm_and_term() {
+        assert_eq!(
+            detect_color_support(None, Some("24bit"), Some("xterm")),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(
+            detect_color_support(None, None, Some("xterm-direct")),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(
+            detect_color_support(None, None, Some("screen-256color")),
+            ColorSupport::Ansi256
+        );
+        assert_eq!(
+            detect_color_support(None, None, Some("xterm")),
+            ColorSupport::Ansi16
+        );
+        assert_eq!(detect_color_support(None, None, None), ColorSupport::Ansi16);
+        assert_eq!(
+            detect_color_support(None, None, Some("dumb")),
+            ColorSupport::Mono
+        );
+    }
+
+    #[test]
+    fn test_rgb_is_mapped_into_the_256_color_palette() {
+        let to_256 = |color| downsample(color, ColorSupport::Ansi256);
+        assert_eq!(to_256(rgb(0, 255, 0)), Some(Color::AnsiValue(46)));
+        assert_eq!(to_256(rgb(255, 135, 0)), Some(Color::AnsiValue(208)));
+        assert_eq!(to_256(rgb(128, 128, 128)), Some(Color::AnsiValue(244)));
+        assert_eq!(to_256(Color::Cyan), Some(Color::Cyan));
+    }
+
+    #[test]
+    fn test_rgb_and_palette_colors_fall_back_to_16_colors() {
+        let to_16 = |color| downsample(color, ColorSupport::Ansi16);
+        assert_eq!(to_16(rgb(0, 180, 0)), Some(Color::DarkGreen));
+        assert_eq!(to_16(rgb(0x2e, 0x34, 0x40)), Some(Color::Black));
+        assert_eq!(to_16(rgb(0xec, 0xef, 0xf4)), Some(Color::White));
+        assert_eq!(to_16(Color::AnsiValue(208)), Some(Color::Yellow));
+        assert_eq!(to_16(Color::AnsiValue(12)), Some(Color::Blue));
+        assert_eq!(to_16(Color::AnsiValue(232)), Some(Color::Black));
+    }
+
+    #[test]
+    fn test_colors_pass_through_or_vanish_at_the_extremes() {
+        assert_eq!(
+            downsample(rgb(1, 2, 3), ColorSupport::TrueColor),
+            Some(rgb(1, 2, 3))
+        );
+        assert_eq!(downsample(Color::Red, ColorSupport::Mono), None);
+    }
+}
@@ -1 +1,10 @@
+pub mod color_logic;
 pub mod style_logic;
+pub mod theme_logic;
+
+#[cfg(test)]
+pub mod color_logic_tests;
+#[cfg(test)]
+pub mod style_logic_tests;
+#[cfg(test)]
+pub mod theme_logic_tests;
@@ -1,5 +1,8 @@
+use crate::styles::theme_logic::Theme;
 use crossterm::style::{Attribute, Color};
+use std::time::Duration;
 
+#[derive(Clone, Debug, PartialEq)]
 pub struct Style {
     pub color: Option<Color>,
     pub bg_color: Option<Color>,
@@ -30,6 +33,33 @@ impl Style {
         self.bg_color = Some(color);
         self
     }
+
+    pub fn attribute(mut self, attribute: Attribute) -> Self {
+        if !self.attributes.contains(&attribute) {
+            self.attributes.push(attribute);
+        }
+        self
+    }
+
+    pub fn bold(self) -> Self {
+        self.attribute(Attribute::Bold)
+    }
+
+    pub fn dim(self) -> Self {
+        self.attribute(Attribute::Dim)
+    }
+
+    pub fn italic(self) -> Self {
+        self.attribute(Attribute::Italic)
+    }
+
+    pub fn underline(self) -> Self {
+        self.attribute(Attribute::Underlined)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.attribute(Attribute::Reverse)
+    }
 }
 
 pub fn get_style(style_name: &str) -> Style {
@@ -41,3 +71,42 @@ pub fn get_style(style_name: &str) -> Style {
         _ => Style::new().fg(Color::White),
     }
 }
+
+const RAINBOW: [Color; 6] = [
+    Color::Red,
+    Color::Yellow,
+    Color::Green,
+    Color::Cyan,
+    Color::Blue,
+    Color::Magenta,
+];
+
+// Style for the character at `index` once `elapsed` has passed, so animated
+// styles can vary both over time and along the text
+pub fn get_animated_style(style_name: &str, elapsed: Duration, index: usize) -> Style {
+    let ticks = elapsed.as_millis() as usize;
+    match style_name {
+        "rainbow" => Style::new().fg(RAINBOW[(index + ticks / 150) % RAINBOW.len()]),
+        "pulse" => pulse(Style::new().fg(Color::Cyan), elapsed),
+        _ => get_style(style_name),
+    }
+}
+
+// Like get_animated_style, but "default" (and any unknown name) draws with
+// the theme's primary role and "pulse" with its accent
+pub fn get_themed_style(style_name: &str, theme: &Theme, elapsed: Duration, index: usize) -> Style {
+    match style_name {
+        "red" | "blue" | "green" | "rainbow" => get_animated_style(style_name, elapsed, index),
+        "pulse" => pulse(theme.accent.clone(), elapsed),
+        _ => theme.primary.clone(),
+    }
+}
+
+// Alternate between bold and dim every half second
+fn pulse(style: Style, elapsed: Duration) -> Style {
+    if (elapsed.as_millis() / 500).is_multiple_of(2) {
+        style.bold()
+    } else {
+        style.dim()
+    }
+}
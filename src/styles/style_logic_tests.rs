@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod style_tests {
+    use crate::styles::style_logic::*;
+    use crossterm::style::{Attribute, Color};
+    use std::time::Duration;
+
+    #[test]
+    fn test_static_styles_ignore_time() {
+        assert_eq!(
+            get_animated_style("red", Duration::from_secs(3), 7),
+            get_style("red")
+        );
+    }
+
+    #[test]
+    fn test_rainbow_shifts_along_text_and_over_time() {
+        let start = Duration::ZERO;
+        assert_ne!(
+            get_animated_style("rainbow", start, 0),
+            get_animated_style("rainbow", start, 1)
+        );
+        assert_eq!(
+            get_animated_style("rainbow", Duration::from_millis(150), 0),
+            get_animated_style("rainbow", start, 1)
+        );
+        assert_eq!(
+            get_animated_style("rainbow", start, 0).color,
+            Some(Color::Red)
+        );
+    }
+
+    #[test]
+    fn test_pulse_alternates_bold_and_dim() {
+        let bright = get_animated_style("pulse", Duration::from_millis(100), 0);
+        let faded = get_animated_style("pulse", Duration::from_millis(600), 0);
+
+        assert!(bright.attributes.contains(&Attribute::Bold));
+        assert!(faded.attributes.contains(&Attribute::Dim));
+    }
+}
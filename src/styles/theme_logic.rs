@@ -0,0 +1,252 @@
+use crate::cli::config_logic::{Config, RoleConfig, ThemeConfig};
+use crate::styles::style_logic::Style;
+use crossterm::style::{Attribute, Color};
+
+pub const BUNDLED_THEMES: &[&str] = &["default", "solarized", "gruvbox", "nord", "monochrome"];
+
+// Colors and attributes for the semantic roles features draw with. The
+// palette supplies the extra colors of multi-colored features (logo, stars,
+// rain) in order of preference.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    pub primary: Style,
+    pub accent: Style,
+    pub help_border: Style,
+    pub help_text: Style,
+    pub dim: Style,
+    pub success: Style,
+    pub warning: Style,
+    pub error: Style,
+    pub background: Option<Color>,
+    pub palette: Vec<Color>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            primary: Style::new().fg(Color::White),
+            accent: Style::new().fg(Color::Cyan),
+            help_border: Style::new().fg(Color::Cyan),
+            help_text: Style::new().fg(Color::Yellow),
+            dim: Style::new().fg(Color::DarkGrey),
+            success: Style::new().fg(Color::Green),
+            warning: Style::new().fg(Color::Yellow),
+            error: Style::new().fg(Color::Red),
+            background: None,
+            palette: vec![
+                Color::Rgb { r: 0, g: 255, b: 0 },
+                Color::Cyan,
+                Color::Yellow,
+                Color::Magenta,
+                Color::Blue,
+            ],
+        }
+    }
+}
+
+impl Theme {
+    // Palette color `index`, wrapping around
+    pub fn palette_color(&self, index: usize) -> Color {
+        if self.palette.is_empty() {
+            return self.primary.color.unwrap_or(Color::White);
+        }
+        self.palette[index % self.palette.len()]
+    }
+}
+
+fn rgb(hex: u32) -> Color {
+    Color::Rgb {
+        r: (hex >> 16) as u8,
+        g: (hex >> 8) as u8,
+        b: hex as u8,
+    }
+}
+
+fn role(hex: u32) -> Style {
+    Style::new().fg(rgb(hex))
+}
+
+// One of BUNDLED_THEMES by name
+pub fn bundled_theme(name: &str) -> Option<Theme> {
+    match name {
+        "default" => Some(Theme::default()),
+        "solarized" => Some(Theme {
+            primary: role(0x93a1a1),
+            accent: role(0x268bd2),
+            help_border: role(0x2aa198),
+            help_text: role(0xb58900),
+            dim: role(0x586e75),
+            success: role(0x859900),
+            warning: role(0xb58900),
+            error: role(0xdc322f),
+            background: Some(rgb(0x002b36)),
+            palette: [0x859900, 0x2aa198, 0xb58900, 0xd33682, 0x268bd2]
+                .map(rgb)
+                .to_vec(),
+        }),
+        "gruvbox" => Some(Theme {
+            primary: role(0xebdbb2),
+            accent: role(0xfe8019),
+            help_border: role(0x8ec07c),
+            help_text: role(0xfabd2f),
+            dim: role(0x928374),
+            success: role(0xb8bb26),
+            warning: role(0xfabd2f),
+            error: role(0xfb4934),
+            background: Some(rgb(0x282828)),
+            palette: [0xb8bb26, 0x8ec07c, 0xfabd2f, 0xd3869b, 0x83a598]
+                .map(rgb)
+                .to_vec(),
+        }),
+        "nord" => Some(Theme {
+            primary: role(0xeceff4),
+            accent: role(0x88c0d0),
+            help_border: role(0x81a1c1),
+            help_text: role(0xebcb8b),
+            dim: role(0x4c566a),
+            success: role(0xa3be8c),
+            warning: role(0xebcb8b),
+            error: role(0xbf616a),
+            background: Some(rgb(0x2e3440)),
+            palette: [0xa3be8c, 0x88c0d0, 0xebcb8b, 0xb48ead, 0x5e81ac]
+                .map(rgb)
+                .to_vec(),
+        }),
+        "monochrome" => Some(Theme {
+            primary: Style::new().fg(Color::White),
+            accent: Style::new().fg(Color::White).bold(),
+            help_border: Style::new().fg(Color::Grey),
+            help_text: Style::new().fg(Color::White),
+            dim: Style::new().fg(Color::DarkGrey),
+            success: Style::new().fg(Color::White),
+            warning: Style::new().fg(Color::Grey),
+            error: Style::new().fg(Color::White).bold(),
+            background: None,
+            palette: vec![Color::White, Color::Grey],
+        }),
+        _ => None,
+    }
+}
+
+// The theme selected by `[screensaver] theme`, falling back to the default
+// theme when the name is unknown
+pub fn theme_for(config: &Config) -> Theme {
+    let name = &config.screensaver.theme;
+    match config.themes.get(name) {
+        Some(custom) => custom_theme(custom),
+        None => bundled_theme(name).unwrap_or_else(|| {
+            log::warn!("Unknown theme '{}', using the default theme", name);
+            Theme::default()
+        }),
+    }
+}
+
+// A `[themes.<name>]` table applied on top of its bundled `base` theme.
+// Unparseable values keep the base theme's; validation reports them.
+pub fn custom_theme(custom: &ThemeConfig) -> Theme {
+    let base = custom.base.as_deref().unwrap_or("default");
+    let mut theme = bundled_theme(base).unwrap_or_default();
+
+    for (role, style) in [
+        (&custom.primary, &mut theme.primary),
+        (&custom.accent, &mut theme.accent),
+        (&custom.help_border, &mut theme.help_border),
+        (&custom.help_text, &mut theme.help_text),
+        (&custom.dim, &mut theme.dim),
+        (&custom.success, &mut theme.success),
+        (&custom.warning, &mut theme.warning),
+        (&custom.error, &mut theme.error),
+    ] {
+        if let Some(role) = role {
+            *style = role_style(role, style);
+        }
+    }
+    if let Some(background) = custom.background.as_deref() {
+        theme.background = parse_color(background).or(theme.background);
+    }
+    if let Some(palette) = &custom.palette {
+        let colors: Vec<Color> = palette.iter().filter_map(|c| parse_color(c)).collect();
+        if !colors.is_empty() {
+            theme.palette = colors;
+        }
+    }
+    theme
+}
+
+fn role_style(role: &RoleConfig, base: &Style) -> Style {
+    match role {
+        RoleConfig::Color(color) => match parse_color(color) {
+            Some(color) => Style::new().fg(color),
+            None => base.clone(),
+        },
+        RoleConfig::Style { fg, bg, attributes } => {
+            let mut style = Style::new();
+            style.color = fg.as_deref().and_then(parse_color).or(base.color);
+            style.bg_color = bg.as_deref().and_then(parse_color);
+            for attribute in attributes.iter().filter_map(|a| parse_attribute(a)) {
+                style = style.attribute(attribute);
+            }
+            style
+        }
+    }
+}
+
+// Color names as crossterm spells them (plus "gray" spellings), hex
+// `#rrggbb`/`#rgb`, or a 256-color palette index
+pub fn parse_color(name: &str) -> Option<Color> {
+    let name = name.trim().to_lowercase().replace("gray", "grey");
+    if let Some(hex) = name.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+    if let Ok(index) = name.parse::<u8>() {
+        return Some(Color::AnsiValue(index));
+    }
+    Color::try_from(name.as_str()).ok()
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let value = u32::from_str_radix(hex, 16).ok()?;
+    match hex.len() {
+        6 => Some(rgb(value)),
+        // Each digit doubled: #f80 is #ff8800
+        3 => {
+            let digit = |shift: u32| ((value >> shift) & 0xf) as u8 * 0x11;
+            Some(Color::Rgb {
+                r: digit(8),
+                g: digit(4),
+                b: digit(0),
+            })
+        }
+        _ => None,
+    }
+}
+
+pub fn parse_attribute(name: &str) -> Option<Attribute> {
+    match name.to_lowercase().as_str() {
+        "bold" => Some(Attribute::Bold),
+        "dim" => Some(Attribute::Dim),
+        "italic" => Some(Attribute::Italic),
+        "underline" => Some(Attribute::Underlined),
+        "reverse" => Some(Attribute::Reverse),
+        _ => None,
+    }
+}
+
+// Scale an RGB color towards black; named colors have no shades and are
+// returned unchanged
+pub fn shade(color: Color, factor: f64) -> Color {
+    match color {
+        Color::Rgb { r, g, b } => {
+            let scale = |c: u8| (c as f64 * factor).round().clamp(0.0, 255.0) as u8;
+            Color::Rgb {
+                r: scale(r),
+                g: scale(g),
+                b: scale(b),
+            }
+        }
+        color => color,
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod theme_tests {
+    use crate::cli::config_logic::{Config, RoleConfig, ThemeConfig};
+    use crate::styles::theme_logic::*;
+    use crossterm::style::{Attribute, Color};
+
+    #[test]
+    fn test_every_bundled_theme_exists() {
+        for name in BUNDLED_THEMES {
+            let theme = bundled_theme(name).expect(name);
+            assert!(!theme.palette.is_empty(), "{} has no palette", name);
+        }
+        assert!(bundled_theme("missing").is_none());
+    }
+
+    #[test]
+    fn test_theme_is_selected_from_config() {
+        let mut config = Config::default();
+        assert_eq!(theme_for(&config), Theme::default());
+
+        config.screensaver.theme = "nord".to_string();
+        assert_eq!(theme_for(&config), bundled_theme("nord").unwrap());
+
+        config.screensaver.theme = "missing".to_string();
+        assert_eq!(theme_for(&config), Theme::default());
+    }
+
+    #[test]
+    fn test_config_theme_overrides_its_base() {
+        let mut config = Config::default();
+        config.screensaver.theme = "mine".to_string();
+        config.themes.insert(
+            "mine".to_string(),
+            ThemeConfig {
+                base: Some("gruvbox".to_string()),
+                accent: Some(RoleConfig::Color("magenta".to_string())),
+                help_text: Some(RoleConfig::Style {
+                    fg: None,
+                    bg: Some("dark_blue".to_string()),
+                    attributes: vec!["bold".to_string(), "underline".to_string()],
+                }),
+                palette: Some(vec!["red".to_string(), "not a color".to_string()]),
+                ..Default::default()
+            },
+        );
+
+        let gruvbox = bundled_theme("gruvbox").unwrap();
+        let theme = theme_for(&config);
+        assert_eq!(theme.primary, gruvbox.primary);
+        assert_eq!(theme.background, gruvbox.background);
+        assert_eq!(theme.accent.color, Some(Color::Magenta));
+        assert_eq!(theme.help_text.color, gruvbox.help_text.color);
+        assert_eq!(theme.help_text.bg_color, Some(Color::DarkBlue));
+        assert!(theme.help_text.attributes.contains(&Attribute::Bold));
+        assert!(theme.help_text.attributes.contains(&Attribute::Underlined));
+        assert_eq!(theme.palette, vec![Color::Red]);
+    }
+
+    #[test]
+    fn test_status_roles_can_be_overridden() {
+        let custom = ThemeConfig {
+            base: Some("monochrome".to_string()),
+            error: Some(RoleConfig::Color("#ff0000".to_string())),
+            ..Default::default()
+        };
+        let theme = custom_theme(&custom);
+        let monochrome = bundled_theme("monochrome").unwrap();
+
+        assert_eq!(theme.error.color, Some(Color::Rgb { r: 255, g: 0, b: 0 }));
+        assert_eq!(theme.success, monochrome.success);
+        assert_eq!(theme.warning, monochrome.warning);
+    }
+
+    #[test]
+    fn test_palette_wraps_around() {
+        let theme = bundled_theme("monochrome").unwrap();
+        assert_eq!(theme.palette_color(0), Color::White);
+        assert_eq!(theme.palette_color(3), Color::Grey);
+
+        let empty = Theme {
+            palette: Vec::new(),
+            ..Theme::default()
+        };
+        assert_eq!(empty.palette_color(2), Color::White);
+    }
+
+    #[test]
+    fn test_colors_and_attributes_parse_by_name() {
+        assert_eq!(parse_color("Dark_Gray"), Some(Color::DarkGrey));
+        assert_eq!(parse_color("cyan"), Some(Color::Cyan));
+        assert_eq!(parse_color("chartreuse"), None);
+        assert_eq!(
+            parse_color("#FF8800"),
+            Some(Color::Rgb {
+                r: 255,
+                g: 136,
+                b: 0
+            })
+        );
+        assert_eq!(parse_color("#f80"), parse_color("#ff8800"));
+        assert_eq!(parse_color("208"), Some(Color::AnsiValue(208)));
+        assert_eq!(parse_color("256"), None);
+        assert_eq!(parse_color("#ff88"), None);
+        assert_eq!(parse_color("#+f80"), None);
+        assert_eq!(parse_attribute("Italic"), Some(Attribute::Italic));
+        assert_eq!(parse_attribute("blink"), None);
+    }
+
+    #[test]
+    fn test_shade_scales_rgb_only() {
+        assert_eq!(
+            shade(
+                Color::Rgb {
+                    r: 0,
+                    g: 255,
+                    b: 100
+                },
+                0.5
+            ),
+            Color::Rgb {
+                r: 0,
+                g: 128,
+                b: 50
+            }
+        );
+        assert_eq!(shade(Color::Cyan, 0.5), Color::Cyan);
+    }
+}